@@ -0,0 +1,12 @@
+-- Add migration script here
+
+CREATE TABLE public.quote_revisions (
+  quote_id integer NOT NULL,
+  revision integer NOT NULL,
+  index smallint NOT NULL,
+  body text NOT NULL,
+  speaker character varying(32) NOT NULL,
+  "timestamp" timestamp without time zone DEFAULT CURRENT_TIMESTAMP NOT NULL,
+  PRIMARY KEY (quote_id, revision, index),
+  CONSTRAINT fk_quote FOREIGN KEY(quote_id) REFERENCES public.quotes(id) ON DELETE CASCADE
+);
@@ -0,0 +1,4 @@
+-- Add migration script here
+
+ALTER TABLE public.hidden ADD COLUMN "timestamp" timestamp without time zone;
+ALTER TABLE public.hidden ALTER COLUMN "timestamp" SET DEFAULT CURRENT_TIMESTAMP;
@@ -0,0 +1,13 @@
+-- Add migration script here
+
+CREATE TABLE public.unhidden (
+  id integer GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+  quote_id integer NOT NULL,
+  hidden_reason text NOT NULL,
+  hidden_actor character varying(32) NOT NULL,
+  hidden_timestamp timestamp without time zone,
+  reason text NOT NULL,
+  actor character varying(32) NOT NULL,
+  "timestamp" timestamp without time zone DEFAULT CURRENT_TIMESTAMP NOT NULL,
+  CONSTRAINT fk_quote FOREIGN KEY(quote_id) REFERENCES public.quotes(id) ON DELETE CASCADE
+);
@@ -0,0 +1,4 @@
+-- Add migration script here
+
+DROP INDEX public.shards_body_tsv_idx;
+ALTER TABLE public.shards DROP COLUMN body_tsv;
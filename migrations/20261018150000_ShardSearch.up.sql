@@ -0,0 +1,4 @@
+-- Add migration script here
+
+ALTER TABLE public.shards ADD COLUMN body_tsv tsvector GENERATED ALWAYS AS (to_tsvector('english', body)) STORED;
+CREATE INDEX shards_body_tsv_idx ON public.shards USING GIN (body_tsv);
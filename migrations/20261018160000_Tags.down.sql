@@ -0,0 +1,4 @@
+-- Add migration script here
+
+DROP TABLE public.quote_tags;
+DROP TABLE public.tags;
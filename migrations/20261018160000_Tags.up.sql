@@ -0,0 +1,16 @@
+-- Add migration script here
+
+CREATE TABLE public.tags (
+  id integer GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+  name character varying(32) UNIQUE NOT NULL
+);
+
+CREATE TABLE public.quote_tags (
+  quote_id integer NOT NULL,
+  tag_id integer NOT NULL,
+  PRIMARY KEY (quote_id, tag_id),
+  CONSTRAINT fk_quote FOREIGN KEY(quote_id) REFERENCES public.quotes(id) ON DELETE CASCADE,
+  CONSTRAINT fk_tag FOREIGN KEY(tag_id) REFERENCES public.tags(id) ON DELETE CASCADE
+);
+
+CREATE INDEX quote_tags_tag_id_idx ON public.quote_tags (tag_id);
@@ -0,0 +1,9 @@
+-- Add migration script here
+
+DELETE FROM public.reports WHERE comment_id IS NOT NULL;
+DROP INDEX public.reports_unique_submitter;
+ALTER TABLE public.reports DROP CONSTRAINT reports_pkey;
+ALTER TABLE public.reports ADD PRIMARY KEY (quote_id, submitter_hash);
+ALTER TABLE public.reports DROP COLUMN comment_id;
+
+DROP TABLE public.comments;
@@ -0,0 +1,18 @@
+-- Add migration script here
+
+CREATE TABLE public.comments (
+  id integer GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+  quote_id integer NOT NULL,
+  author character varying(32) NOT NULL,
+  body text NOT NULL,
+  "timestamp" timestamp without time zone DEFAULT CURRENT_TIMESTAMP NOT NULL,
+  CONSTRAINT fk_quote FOREIGN KEY(quote_id) REFERENCES public.quotes(id) ON DELETE CASCADE
+);
+
+CREATE INDEX comments_quote_id_idx ON public.comments (quote_id);
+
+ALTER TABLE public.reports ADD COLUMN comment_id integer;
+ALTER TABLE public.reports ADD CONSTRAINT fk_comment FOREIGN KEY(comment_id) REFERENCES public.comments(id) ON DELETE CASCADE;
+ALTER TABLE public.reports DROP CONSTRAINT reports_pkey;
+ALTER TABLE public.reports ADD PRIMARY KEY (id);
+CREATE UNIQUE INDEX reports_unique_submitter ON public.reports (quote_id, COALESCE(comment_id, 0), submitter_hash);
@@ -0,0 +1,10 @@
+-- Add migration script here
+
+CREATE TABLE public.reactions (
+  quote_id integer NOT NULL,
+  username character varying(32) NOT NULL,
+  emoji character varying(16) NOT NULL,
+  "timestamp" timestamp without time zone DEFAULT CURRENT_TIMESTAMP NOT NULL,
+  PRIMARY KEY (quote_id, username, emoji),
+  CONSTRAINT fk_quote FOREIGN KEY(quote_id) REFERENCES public.quotes(id) ON DELETE CASCADE
+);
@@ -0,0 +1,4 @@
+-- Add migration script here
+
+DROP TABLE public.notification_outbox;
+DROP TYPE public.notification_status;
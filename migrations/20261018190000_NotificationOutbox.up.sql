@@ -0,0 +1,18 @@
+-- Add migration script here
+
+CREATE TYPE public.notification_status AS ENUM ('pending', 'delivered', 'dead');
+
+CREATE TABLE public.notification_outbox (
+  id integer GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+  username character varying(32) NOT NULL,
+  channel character varying(16) NOT NULL,
+  body text NOT NULL,
+  status public.notification_status DEFAULT 'pending' NOT NULL,
+  attempts integer DEFAULT 0 NOT NULL,
+  next_attempt timestamp without time zone DEFAULT CURRENT_TIMESTAMP NOT NULL,
+  last_error text,
+  "timestamp" timestamp without time zone DEFAULT CURRENT_TIMESTAMP NOT NULL,
+  delivered timestamp without time zone
+);
+
+CREATE INDEX notification_outbox_pending_idx ON public.notification_outbox (next_attempt) WHERE status = 'pending';
@@ -0,0 +1,4 @@
+-- Add migration script here
+
+DROP TABLE public.notification_preferences;
+DROP TYPE public.notification_event;
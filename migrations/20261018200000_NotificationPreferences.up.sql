@@ -0,0 +1,11 @@
+-- Add migration script here
+
+CREATE TYPE public.notification_event AS ENUM ('quoted', 'upvoted', 'moderated', 'commented');
+
+CREATE TABLE public.notification_preferences (
+  username character varying(32) NOT NULL,
+  event public.notification_event NOT NULL,
+  channel character varying(16) NOT NULL,
+  enabled boolean NOT NULL,
+  PRIMARY KEY (username, event, channel)
+);
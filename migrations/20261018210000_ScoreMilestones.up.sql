@@ -0,0 +1,20 @@
+-- Add migration script here
+
+CREATE TABLE public.score_milestones (
+  quote_id integer NOT NULL,
+  milestone integer NOT NULL,
+  "timestamp" timestamp without time zone DEFAULT CURRENT_TIMESTAMP NOT NULL,
+  PRIMARY KEY (quote_id, milestone),
+  CONSTRAINT fk_quote FOREIGN KEY(quote_id) REFERENCES public.quotes(id) ON DELETE CASCADE
+);
+
+-- Quotes that already passed the default milestones shouldn't notify again on their next vote
+INSERT INTO public.score_milestones (quote_id, milestone)
+SELECT t.quote_id, m.milestone
+FROM (
+  SELECT quote_id, SUM(CASE WHEN vote='upvote' THEN 1 WHEN vote='downvote' THEN -1 ELSE 0 END) AS score
+  FROM public.votes
+  GROUP BY quote_id
+) t
+CROSS JOIN (VALUES (10), (25), (50)) AS m(milestone)
+WHERE m.milestone <= t.score;
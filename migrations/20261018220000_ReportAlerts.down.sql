@@ -0,0 +1,9 @@
+-- Add migration script here
+
+DROP TABLE public.report_alerts;
+
+DELETE FROM public.notification_preferences WHERE event = 'reported';
+ALTER TYPE public.notification_event RENAME TO notification_event_old;
+CREATE TYPE public.notification_event AS ENUM ('quoted', 'upvoted', 'moderated', 'commented');
+ALTER TABLE public.notification_preferences ALTER COLUMN event TYPE public.notification_event USING event::text::public.notification_event;
+DROP TYPE public.notification_event_old;
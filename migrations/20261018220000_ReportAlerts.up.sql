@@ -0,0 +1,9 @@
+-- Add migration script here
+
+ALTER TYPE public.notification_event ADD VALUE 'reported';
+
+CREATE TABLE public.report_alerts (
+  quote_id integer PRIMARY KEY,
+  "timestamp" timestamp without time zone DEFAULT CURRENT_TIMESTAMP NOT NULL,
+  CONSTRAINT fk_quote FOREIGN KEY(quote_id) REFERENCES public.quotes(id) ON DELETE CASCADE
+);
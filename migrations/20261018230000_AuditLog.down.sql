@@ -0,0 +1,5 @@
+-- Add migration script here
+
+DROP TABLE public.audit_log;
+DROP FUNCTION public.audit_log_append_only;
+DROP TYPE public.audit_action;
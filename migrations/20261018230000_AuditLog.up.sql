@@ -0,0 +1,27 @@
+-- Add migration script here
+
+CREATE TYPE public.audit_action AS ENUM ('hide', 'unhide', 'resolve', 'delete', 'kevlar');
+
+CREATE TABLE public.audit_log (
+  id integer GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+  actor character varying(32) NOT NULL,
+  action public.audit_action NOT NULL,
+  -- not a foreign key, entries must outlive the quotes they refer to
+  quote_id integer,
+  details text,
+  "timestamp" timestamp without time zone DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+CREATE INDEX audit_log_actor ON public.audit_log (actor);
+CREATE INDEX audit_log_quote_id ON public.audit_log (quote_id);
+CREATE INDEX audit_log_timestamp ON public.audit_log ("timestamp");
+
+CREATE FUNCTION public.audit_log_append_only() RETURNS trigger AS $$
+BEGIN
+  RAISE EXCEPTION 'audit_log is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER audit_log_append_only
+  BEFORE UPDATE OR DELETE ON public.audit_log
+  FOR EACH ROW EXECUTE FUNCTION public.audit_log_append_only();
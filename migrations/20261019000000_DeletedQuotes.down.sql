@@ -0,0 +1,6 @@
+-- Add migration script here
+
+DROP TABLE public.deleted_quotes;
+
+ALTER TABLE public.hidden DROP CONSTRAINT fk_quote;
+ALTER TABLE public.hidden ADD CONSTRAINT fk_quote FOREIGN KEY(quote_id) REFERENCES public.quotes(id) ON DELETE SET NULL;
@@ -0,0 +1,13 @@
+-- Add migration script here
+
+-- hidden.quote_id is the primary key, so it can't be set to null when its
+-- quote is deleted
+ALTER TABLE public.hidden DROP CONSTRAINT fk_quote;
+ALTER TABLE public.hidden ADD CONSTRAINT fk_quote FOREIGN KEY(quote_id) REFERENCES public.quotes(id) ON DELETE CASCADE;
+
+CREATE TABLE public.deleted_quotes (
+  id integer PRIMARY KEY,
+  reason text NOT NULL,
+  actor character varying(32) NOT NULL,
+  "timestamp" timestamp without time zone DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
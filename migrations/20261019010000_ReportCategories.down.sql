@@ -0,0 +1,12 @@
+-- Add migration script here
+
+DROP INDEX public.reports_category;
+
+ALTER TABLE public.reports DROP COLUMN notes;
+ALTER TABLE public.reports DROP COLUMN outcome;
+UPDATE public.reports SET reason = category::text WHERE reason IS NULL;
+ALTER TABLE public.reports ALTER COLUMN reason SET NOT NULL;
+ALTER TABLE public.reports DROP COLUMN category;
+
+DROP TYPE public.report_outcome;
+DROP TYPE public.report_category;
@@ -0,0 +1,11 @@
+-- Add migration script here
+
+CREATE TYPE public.report_category AS ENUM ('harassment', 'private_info', 'inaccurate', 'other');
+CREATE TYPE public.report_outcome AS ENUM ('dismissed', 'hidden', 'unhidden', 'other');
+
+ALTER TABLE public.reports ADD COLUMN category public.report_category DEFAULT 'other' NOT NULL;
+ALTER TABLE public.reports ALTER COLUMN reason DROP NOT NULL;
+ALTER TABLE public.reports ADD COLUMN outcome public.report_outcome;
+ALTER TABLE public.reports ADD COLUMN notes text;
+
+CREATE INDEX reports_category ON public.reports (category);
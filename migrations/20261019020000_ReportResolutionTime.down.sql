@@ -0,0 +1,5 @@
+-- Add migration script here
+
+DROP INDEX public.reports_resolved;
+
+ALTER TABLE public.reports DROP COLUMN resolved;
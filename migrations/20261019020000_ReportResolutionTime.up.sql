@@ -0,0 +1,5 @@
+-- Add migration script here
+
+ALTER TABLE public.reports ADD COLUMN resolved timestamp without time zone;
+
+CREATE INDEX reports_resolved ON public.reports (id) WHERE resolver IS NOT NULL;
@@ -0,0 +1,11 @@
+-- Add migration script here
+
+DELETE FROM public.reports WHERE comment_id IS NOT NULL AND comment_id NOT IN (SELECT id FROM public.comments);
+ALTER TABLE public.reports ADD CONSTRAINT fk_comment FOREIGN KEY(comment_id) REFERENCES public.comments(id) ON DELETE CASCADE;
+
+ALTER TYPE public.report_outcome RENAME TO report_outcome_old;
+CREATE TYPE public.report_outcome AS ENUM ('dismissed', 'hidden', 'unhidden', 'other');
+ALTER TABLE public.reports ALTER COLUMN outcome TYPE public.report_outcome USING (
+  CASE WHEN outcome::text = 'removed' THEN 'other' ELSE outcome::text END
+)::public.report_outcome;
+DROP TYPE public.report_outcome_old;
@@ -0,0 +1,6 @@
+-- Add migration script here
+
+ALTER TYPE public.report_outcome ADD VALUE 'removed';
+
+-- resolved reports are kept after moderators remove the comment they were about
+ALTER TABLE public.reports DROP CONSTRAINT fk_comment;
@@ -0,0 +1,8 @@
+-- Add migration script here
+
+CREATE TABLE public.daily_quotes (
+  date date NOT NULL DEFAULT CURRENT_DATE,
+  quote_id integer NOT NULL,
+  CONSTRAINT daily_quotes_pkey PRIMARY KEY (date),
+  CONSTRAINT fk_quote FOREIGN KEY(quote_id) REFERENCES public.quotes(id) ON DELETE CASCADE
+);
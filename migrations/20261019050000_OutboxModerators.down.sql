@@ -0,0 +1,4 @@
+-- Add migration script here
+
+DELETE FROM public.notification_outbox WHERE username IS NULL;
+ALTER TABLE public.notification_outbox ALTER COLUMN username SET NOT NULL;
@@ -0,0 +1,4 @@
+-- Add migration script here
+
+-- rows without a username go to every moderator, looked up when they're delivered
+ALTER TABLE public.notification_outbox ALTER COLUMN username DROP NOT NULL;
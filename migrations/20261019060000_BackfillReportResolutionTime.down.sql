@@ -0,0 +1,3 @@
+-- Add migration script here
+
+-- backfilled times can't be told apart from recorded ones, so they are kept
@@ -0,0 +1,11 @@
+-- Add migration script here
+
+-- reports resolved before resolution times were tracked take the time of the
+-- resolve logged for them, and stay NULL when nothing was logged
+UPDATE public.reports r
+SET resolved = (
+  SELECT MIN(a.timestamp) FROM public.audit_log a
+  WHERE a.action = 'resolve' AND a.quote_id = r.quote_id
+  AND a.actor = r.resolver AND a.timestamp >= r.timestamp
+)
+WHERE r.resolver IS NOT NULL AND r.resolved IS NULL;
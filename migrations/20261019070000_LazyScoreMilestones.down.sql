@@ -0,0 +1,3 @@
+-- Add migration script here
+
+-- milestones are recorded again as quotes are voted on, so there's nothing to restore
@@ -0,0 +1,6 @@
+-- Add migration script here
+
+-- milestones were backfilled here by net score for a fixed list. They are now
+-- counted in upvotes and ones a quote already passed are recorded without
+-- notifying on its next vote, so start over from the configured list
+DELETE FROM public.score_milestones;
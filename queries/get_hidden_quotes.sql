@@ -0,0 +1,96 @@
+select
+    pq.id as "id!",
+    s.index as "index!",
+    pq.submitter as "submitter!",
+    pq.timestamp as "timestamp!",
+    s.body as "body!",
+    s.speaker as "speaker!",
+    pq.reason as "hidden_reason: Option<String>",
+    pq.actor as "hidden_actor: Option<String>",
+    pq.hidden_timestamp as "hidden_timestamp?",
+    v.vote as "vote: Option<Vote>",
+    (case when t.score is null then 0 else t.score end) as "score!",
+    (case when f.username is null then false else true end) as "favorited!",
+    (select count(*) from comments c where c.quote_id = pq.id) as "comment_count!",
+    array(
+        select re.emoji
+        from reactions re
+        where re.quote_id = pq.id
+        group by re.emoji
+        order by re.emoji
+    ) as "reaction_emoji!",
+    array(
+        select count(*)
+        from reactions re
+        where re.quote_id = pq.id
+        group by re.emoji
+        order by re.emoji
+    ) as "reaction_counts!",
+    array(
+        select re.emoji
+        from reactions re
+        where re.quote_id = pq.id and re.username = $6
+        order by re.emoji
+    ) as "user_reactions!",
+    array(
+        select t.name
+        from quote_tags qt
+        join tags t on t.id = qt.tag_id
+        where qt.quote_id = pq.id
+        order by t.name
+    ) as "tags!",
+    null::float8 as "sort_key?",
+    null::int8 as "total?"
+from
+    (
+        select
+            q.id,
+            q.submitter,
+            q.timestamp,
+            h.reason,
+            h.actor,
+            h.timestamp as hidden_timestamp
+        from quotes q
+        inner join hidden h on h.quote_id = q.id
+        where
+            case when $2::int4 > 0 then q.id < $2::int4 else true end
+            and ($7::int4 is null or q.id < $7::int4)
+            and h.actor like $3
+            -- quotes hidden before hide times were recorded match any range
+            and (
+                $4::timestamp is null
+                or h.timestamp is null
+                or h.timestamp >= $4::timestamp
+            )
+            and (
+                $5::timestamp is null
+                or h.timestamp is null
+                or h.timestamp < $5::timestamp
+            )
+        order by q.id desc
+        limit $1
+    ) as pq
+left join shards s on s.quote_id = pq.id
+left join
+    (select quote_id, vote from votes where submitter = $6) v on v.quote_id = pq.id
+left join
+    (
+        select
+            quote_id,
+            sum(
+                case
+                    when vote = 'upvote'
+                    then 1
+                    when vote = 'downvote'
+                    then -1
+                    else 0
+                end
+            ) as score
+        from votes
+        group by quote_id
+    ) t
+    on t.quote_id = pq.id
+left join
+    (select quote_id, username from favorites where username = $6) f
+    on f.quote_id = pq.id
+order by pq.id desc, s.index
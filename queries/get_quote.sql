@@ -0,0 +1,93 @@
+select
+    pq.id as "id!",
+    s.index as "index!",
+    pq.submitter as "submitter!",
+    pq.timestamp as "timestamp!",
+    s.body as "body!",
+    s.speaker as "speaker!",
+    hidden.reason as "hidden_reason: Option<String>",
+    hidden.actor as "hidden_actor: Option<String>",
+    hidden.timestamp as "hidden_timestamp?",
+    v.vote as "vote: Option<Vote>",
+    (case when t.score is null then 0 else t.score end) as "score!",
+    (case when f.username is null then false else true end) as "favorited!",
+    (select count(*) from comments c where c.quote_id = pq.id) as "comment_count!",
+    array(
+        select re.emoji
+        from reactions re
+        where re.quote_id = pq.id
+        group by re.emoji
+        order by re.emoji
+    ) as "reaction_emoji!",
+    array(
+        select count(*)
+        from reactions re
+        where re.quote_id = pq.id
+        group by re.emoji
+        order by re.emoji
+    ) as "reaction_counts!",
+    array(
+        select re.emoji
+        from reactions re
+        where re.quote_id = pq.id and re.username = $2
+        order by re.emoji
+    ) as "user_reactions!",
+    array(
+        select t.name
+        from quote_tags qt
+        join tags t on t.id = qt.tag_id
+        where qt.quote_id = pq.id
+        order by t.name
+    ) as "tags!",
+    null::float8 as "sort_key?",
+    null::int8 as "total?"
+from
+    (
+        select *
+        from quotes q
+        where
+            q.id = any($1::int4[])
+            and case
+                when $3
+                then true
+                else
+                    (
+                        case
+                            when
+                                q.id in (select quote_id from hidden)
+                                and (
+                                    q.submitter = $2
+                                    or $2
+                                    in (select speaker from shards where quote_id = q.id)
+                                )
+                            then true
+                            else q.id not in (select quote_id from hidden)
+                        end
+                    )
+            end
+    ) as pq
+left join hidden on hidden.quote_id = pq.id
+left join shards s on s.quote_id = pq.id
+left join
+    (select quote_id, vote from votes where submitter = $2) v on v.quote_id = pq.id
+left join
+    (
+        select
+            quote_id,
+            sum(
+                case
+                    when vote = 'upvote'
+                    then 1
+                    when vote = 'downvote'
+                    then -1
+                    else 0
+                end
+            ) as score
+        from votes
+        group by quote_id
+    ) t
+    on t.quote_id = pq.id
+left join
+    (select quote_id, username from favorites where username = $2) f
+    on f.quote_id = pq.id
+order by pq.timestamp desc, pq.id desc, s.index
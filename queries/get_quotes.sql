@@ -7,100 +7,179 @@ select
     s.speaker as "speaker!",
     hidden.reason as "hidden_reason: Option<String>",
     hidden.actor as "hidden_actor: Option<String>",
+    hidden.timestamp as "hidden_timestamp?",
     v.vote as "vote: Option<Vote>",
     (case when pq.score is null then 0 else pq.score end) as "score!",
-    (case when f.username is null then false else true end) as "favorited!"
+    (case when f.username is null then false else true end) as "favorited!",
+    (select count(*) from comments c where c.quote_id = pq.id) as "comment_count!",
+    array(
+        select re.emoji
+        from reactions re
+        where re.quote_id = pq.id
+        group by re.emoji
+        order by re.emoji
+    ) as "reaction_emoji!",
+    array(
+        select count(*)
+        from reactions re
+        where re.quote_id = pq.id
+        group by re.emoji
+        order by re.emoji
+    ) as "reaction_counts!",
+    array(
+        select re.emoji
+        from reactions re
+        where re.quote_id = pq.id and re.username = $8
+        order by re.emoji
+    ) as "user_reactions!",
+    array(
+        select t.name
+        from quote_tags qt
+        join tags t on t.id = qt.tag_id
+        where qt.quote_id = pq.id
+        order by t.name
+    ) as "tags!",
+    pq.sort_key as "sort_key?",
+    pq.total as "total?"
 from
     (
         select *
         from
             (
                 select
-                    id,
-                    submitter,
-                    timestamp,
-                    (case when quote_id is not null then true else false end) as hidden
-                from quotes as _q
-                left join (select quote_id from hidden) _h on _q.id = _h.quote_id
-            ) as q
-        left join
-            (
-                select
-                    quote_id,
-                    sum(
+                    *,
+                    (
                         case
-                            when vote = 'upvote'
-                            then 1
-                            when vote = 'downvote'
-                            then -1
-                            else 0
+                            when $12::text = 'votes' and $13::bool
+                            then coalesce(score, 0)
+                            when $12::text = 'votes' and not $13::bool
+                            then -1 * coalesce(score, 0)
+                            when $12::text = 'relevance' and $13::bool
+                            then coalesce(rank, 0)
+                            when $12::text = 'relevance' and not $13::bool
+                            then -1 * coalesce(rank, 0)
+                            when $13::bool
+                            then extract(epoch from timestamp)
+                            else -1 * extract(epoch from timestamp)
                         end
-                    ) as score
-                from votes
-                group by quote_id
-            ) as t
-            on t.quote_id = q.id
-        where
-            case
-                when $7 and $6 and $9
-                then q.hidden
-                when $7 and $6
-                then
+                    )::float8 as sort_key,
+                    count(*) over () as total
+                from
+                    (
+                        select
+                            id,
+                            submitter,
+                            timestamp,
+                            (
+                                case when quote_id is not null then true else false end
+                            ) as hidden
+                        from quotes as _q
+                        left join (select quote_id from hidden) _h on _q.id = _h.quote_id
+                    ) as q
+                left join
+                    (
+                        select
+                            quote_id,
+                            sum(
+                                case
+                                    when vote = 'upvote'
+                                    then 1
+                                    when vote = 'downvote'
+                                    then -1
+                                    else 0
+                                end
+                            ) as score
+                        from votes
+                        group by quote_id
+                    ) as t
+                    on t.quote_id = q.id
+                left join
+                    (
+                        select
+                            quote_id as rank_quote_id,
+                            max(
+                                ts_rank(body_tsv, websearch_to_tsquery('english', $3))
+                            ) as rank
+                        from shards
+                        where
+                            $3 <> '' and body_tsv @@ websearch_to_tsquery('english', $3)
+                        group by quote_id
+                    ) as r
+                    on r.rank_quote_id = q.id
+                where
                     case
-                        when
+                        when $7 and $6 and $9
+                        then q.hidden
+                        when $7 and $6
+                        then
+                            case
+                                when
+                                    (
+                                        q.submitter = $8
+                                        or $8
+                                        in (select speaker from shards where quote_id = q.id)
+                                    )
+                                then q.hidden
+                                else false
+                            end
+                        when $7 and not $6
+                        then not q.hidden
+                        else
                             (
-                                q.submitter = $8
-                                or $8
-                                in (select speaker from shards where quote_id = q.id)
+                                case
+                                    when
+                                        q.hidden
+                                        and (
+                                            q.submitter = $8
+                                            or $8 in (
+                                                select speaker
+                                                from shards
+                                                where quote_id = q.id
+                                            )
+                                        )
+                                    then q.hidden
+                                    else not q.hidden
+                                end
                             )
-                        then q.hidden
-                        else false
                     end
-                when $7 and not $6
-                then not q.hidden
-                else
-                    (
-                        case
-                            when
-                                q.hidden
-                                and (
-                                    q.submitter = $8
-                                    or $8 in (
-                                        select speaker from shards where quote_id = q.id
-                                    )
-                                )
-                            then q.hidden
-                            else not q.hidden
-                        end
+                    and submitter like $5
+                    and (
+                        submitter like $10
+                        or q.id in (select quote_id from shards s where speaker like $10)
                     )
-            end
-            and case when $2::int4 > 0 then q.id < $2::int4 else true end
-            and submitter like $5
-            and (
-                submitter like $10
-                or q.id in (select quote_id from shards s where speaker like $10)
-            )
-            and q.id
-            in (select quote_id from shards where body ilike $3 and speaker like $4)
-            and case
-                when $11
-                then q.id in (select quote_id from favorites where username = $8)
+                    and q.id in (
+                        select quote_id
+                        from shards
+                        where
+                            ($3 = '' or body_tsv @@ websearch_to_tsquery('english', $3))
+                            and speaker like $4
+                    )
+                    and case
+                        when $11
+                        then q.id in (select quote_id from favorites where username = $8)
+                        else true
+                    end
+                    and (
+                        $16::varchar is null
+                        or q.id in (
+                            select qt.quote_id
+                            from quote_tags qt
+                            join tags t on t.id = qt.tag_id
+                            where t.name = $16::varchar
+                        )
+                    )
+            ) as sq
+        -- paging is applied after counting so total doesn't depend on the page
+        where
+            case
+                when $15::int4 is not null
+                then
+                    sq.sort_key > $14::float8
+                    or (sq.sort_key = $14::float8 and sq.id < $15::int4)
                 else true
             end
-        order by
-            (
-                case
-                    when $12::bool and $13::bool
-                    then score
-                    when $12::bool and not $13::bool
-                    then -1 * score
-                    when not $12::bool and $13::bool
-                    then extract(epoch from timestamp)
-                    when not $12::bool and not $13::bool
-                    then -1 * extract(epoch from timestamp)
-                end
-            ),
-            q.id desc
+            and case when $2::int4 > 0 then sq.id < $2::int4 else true end
+        order by sq.sort_key, sq.id desc
         limit $1
     ) as pq
 left join hidden on hidden.quote_id = pq.id
@@ -110,18 +189,4 @@ left join
 left join
     (select quote_id, username from favorites where username = $8) f
     on f.quote_id = pq.id
-order by
-    (
-        case
-            when $12::bool and $13::bool
-            then score
-            when $12::bool and not $13::bool
-            then -1 * score
-            when not $12::bool and $13::bool
-            then extract(epoch from timestamp)
-            when not $12::bool and not $13::bool
-            then -1 * extract(epoch from timestamp)
-        end
-    ),
-    pq.id,
-    s.index
+order by pq.sort_key, pq.id desc, s.index
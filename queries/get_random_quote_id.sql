@@ -0,0 +1,75 @@
+with
+    pivot as (
+        select min(id) + floor(random() * (max(id) - min(id) + 1))::int4 as id
+        from quotes
+    ),
+    -- inlined into both lookups below so each walks the primary key and stops
+    -- at its first match
+    matching as not materialized (
+        select q.id
+        from quotes q
+        where
+            q.id not in (select quote_id from hidden)
+            and q.submitter like $3
+            and (
+                q.submitter like $4
+                or q.id in (select quote_id from shards where speaker like $4)
+            )
+            and q.id in (
+                select quote_id
+                from shards
+                where
+                    ($1 = '' or body_tsv @@ websearch_to_tsquery('english', $1))
+                    and speaker like $2
+            )
+            and (
+                $5::varchar is null
+                or q.id in (
+                    select qt.quote_id
+                    from quote_tags qt
+                    join tags t on t.id = qt.tag_id
+                    where t.name = $5::varchar
+                )
+            )
+            and (
+                $6::int8 is null
+                or coalesce(
+                    (
+                        select
+                            sum(
+                                case
+                                    when vote = 'upvote'
+                                    then 1
+                                    when vote = 'downvote'
+                                    then -1
+                                    else 0
+                                end
+                            )
+                        from votes
+                        where quote_id = q.id
+                    ),
+                    0
+                )
+                >= $6::int8
+            )
+            and q.submitter <> all($7)
+            and not exists (
+                select 1 from shards s where s.quote_id = q.id and s.speaker = any($7)
+            )
+    )
+select id as "id!"
+from
+    (
+        (
+            select id, 0 as pass
+            from matching
+            where id >= (select id from pivot)
+            order by id
+            limit 1
+        )
+        union all
+        -- wrap around to the start when nothing matches at or after the pivot
+        (select id, 1 as pass from matching order by id limit 1)
+    ) candidates
+order by pass
+limit 1
@@ -0,0 +1,94 @@
+with
+    -- Quotes counted towards any leaderboard: visible, inside the window and
+    -- not involving anyone with kevlar
+    eligible as (
+        select q.id, q.submitter
+        from quotes q
+        where
+            q.id not in (select quote_id from hidden)
+            and ($1::timestamp is null or q.timestamp >= $1::timestamp)
+            and q.submitter <> all($2)
+            and not exists (
+                select 1 from shards s where s.quote_id = q.id and s.speaker = any($2)
+            )
+    ),
+    most_quoted as (
+        select s.speaker as uid, count(distinct s.quote_id) as count
+        from shards s
+        join eligible e on e.id = s.quote_id
+        group by s.speaker
+        order by 2 desc, s.speaker
+        limit $3
+    ),
+    top_submitters as (
+        select submitter as uid, count(*) as count
+        from eligible
+        group by submitter
+        order by 2 desc, submitter
+        limit $3
+    ),
+    top_quotes as (
+        select
+            v.quote_id as id,
+            sum(
+                case
+                    when v.vote = 'upvote' then 1 when v.vote = 'downvote' then -1 else 0
+                end
+            ) as count
+        from votes v
+        join eligible e on e.id = v.quote_id
+        group by v.quote_id
+        having
+            sum(
+                case
+                    when v.vote = 'upvote' then 1 when v.vote = 'downvote' then -1 else 0
+                end
+            )
+            > 0
+        order by 2 desc, v.quote_id desc
+        limit $3
+    ),
+    most_favorited as (
+        select f.quote_id as id, count(*) as count
+        from favorites f
+        join eligible e on e.id = f.quote_id
+        group by f.quote_id
+        order by 2 desc, f.quote_id desc
+        limit $3
+    )
+select board as "board!", uid, id, count as "count!"
+from
+    (
+        select
+            'most_quoted' as board,
+            uid,
+            null::int4 as id,
+            count,
+            row_number() over (order by count desc, uid) as rank
+        from most_quoted
+        union all
+        select
+            'top_submitters',
+            uid,
+            null::int4,
+            count,
+            row_number() over (order by count desc, uid)
+        from top_submitters
+        union all
+        select
+            'top_quotes',
+            null::varchar,
+            id,
+            count,
+            row_number() over (order by count desc, id desc)
+        from top_quotes
+        union all
+        select
+            'most_favorited',
+            null::varchar,
+            id,
+            count,
+            row_number() over (order by count desc, id desc)
+        from most_favorited
+    ) boards
+order by board, rank
@@ -1,36 +1,53 @@
-use std::collections::{BTreeSet, HashMap};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fmt::{self, Display};
+use std::io;
 
 use actix_web::body::MessageBody;
 use actix_web::{
     delete, get,
     http::StatusCode,
     post, put,
-    web::{self, Data, Json, Path},
+    web::{self, Bytes, Data, Json, Path},
     HttpResponse, Responder, ResponseError,
 };
+use futures::StreamExt;
 use log::{log, Level};
 use sha3::{Digest, Sha3_256};
-use sqlx::{query, query_as, query_file_as, Connection, Postgres, Transaction};
+use sqlx::{
+    postgres::PgQueryResult, query, query_as, query_file_as, Connection, Pool, Postgres,
+    Transaction,
+};
 
 use crate::auth::{any_user_has_kevlar, clear_kevlar_cache, edit_kevlar_cache, get_kevlar_users};
 use crate::{
     api::{
         db::{log_query, log_query_as, open_transaction},
-        pings::send_ping,
+        export::ExportFormat,
     },
     app::AppState,
     auth::{CSHAuth, User, SECURITY_ENABLED},
     ldap,
+    notify::outbox,
     schema::{
         api::{
-            FetchParams, Hidden, NewQuote, QuoteResponse, QuoteShardResponse, Reason,
-            ReportResponse, ReportedQuoteResponse, ResolveParams, UserResponse, VersionResponse,
-            VoteParams,
+            AuditParams, AuditResponse, CommentResponse, ExportParams, FetchParams, Hidden,
+            HiddenFilters, HiddenParams, ImportErrorResponse, ImportQuote, ImportResponse,
+            NewComment, NewQuote, NewQuoteShard, NewReport, NewTag, OutboxParams, OutboxResponse,
+            OwnReportResponse, PaginatedResponse, PreferenceResponse, QuoteFilters, QuoteResponse,
+            QuoteRevisionResponse, QuoteShardResponse, QuoteStatResponse, RandomParams,
+            ReactionParams, ReactionResponse, Reason, ReportFilters, ReportParams, ReportResponse,
+            ReportStatus, ReportedQuoteResponse, Resolution, ResolveParams, ResolvedReportFilters,
+            ResolvedReportParams, ResolvedReportResponse, ShardChangeResponse, StatsParams,
+            StatsResponse, TagParams, TagResponse, UserProfileResponse, UserResponse,
+            UserStatResponse, VersionResponse, VoteParams,
+        },
+        db::{
+            AuditAction, BoardCount, Comment, NotificationEvent, NotificationStatus, OwnReport,
+            Preference, QuoteShard, ReportCategory, ReportOutcome, ReportedQuoteShard,
+            ResolvedReport, RevisionShard, UserCount, UserStats, Vote, ID,
         },
-        db::{QuoteShard, ReportedQuoteShard, Vote, ID},
     },
-    utils::is_valid_username,
+    utils::{is_valid_tag, is_valid_username, QuoteCursor, REACTION_ALLOWLIST, SCORE_MILESTONES},
 };
 
 async fn shards_to_quotes(
@@ -98,9 +115,22 @@ async fn shards_to_quotes(
                     Some(Hidden {
                         actor,
                         reason: shard.hidden_reason.clone()?,
+                        timestamp: shard.hidden_timestamp,
                     })
                 }),
                 favorited: shard.favorited,
+                tags: shard.tags.clone(),
+                comment_count: shard.comment_count,
+                reactions: shard
+                    .reaction_emoji
+                    .iter()
+                    .zip(shard.reaction_counts.iter())
+                    .map(|(emoji, count)| ReactionResponse {
+                        emoji: emoji.clone(),
+                        count: *count,
+                        reacted: shard.user_reactions.contains(emoji),
+                    })
+                    .collect(),
             });
         } else {
             quotes.last_mut().unwrap().shards.push(QuoteShardResponse {
@@ -112,31 +142,403 @@ async fn shards_to_quotes(
     Ok(quotes)
 }
 
+async fn validate_shards(
+    state: &AppState,
+    shards: &[NewQuoteShard],
+    user: &User,
+) -> Result<(), HttpResponse> {
+    if shards.is_empty() {
+        return Err(HttpResponse::BadRequest().body("No quote shards specified"));
+    }
+    if shards.len() > 6 {
+        return Err(HttpResponse::BadRequest().body("Maximum of 6 shards exceeded."));
+    }
+    for shard in shards {
+        if !is_valid_username(shard.speaker.as_str()) {
+            return Err(
+                HttpResponse::BadRequest().body("Invalid speaker username format specified.")
+            );
+        }
+        if user.preferred_username == shard.speaker {
+            return Err(HttpResponse::BadRequest().body("Erm... maybe don't quote yourself?"));
+        }
+    }
+    if !is_valid_username(user.preferred_username.as_str()) {
+        return Err(HttpResponse::BadRequest()
+            .body("Invalid submitter username specified. SHOULD NEVER HAPPEN!"));
+    }
+    let mut users: Vec<String> = shards.iter().map(|x| x.speaker.clone()).collect();
+    let kevlar = match any_user_has_kevlar(&state.db, users.as_slice()).await {
+        Ok(b) => b,
+        Err(e) => return Err(HttpResponse::InternalServerError().body(e.to_string())),
+    };
+    if kevlar {
+        return Err(HttpResponse::BadRequest().body("One or more speakers cannot be quoted"));
+    }
+    users.push(user.preferred_username.clone());
+    match ldap::users_exist(&state.ldap, BTreeSet::from_iter(users)).await {
+        Ok(exists) => {
+            if !exists {
+                return Err(HttpResponse::BadRequest().body("Some users submitted do not exist."));
+            }
+        }
+        Err(err) => return Err(HttpResponse::InternalServerError().body(err.to_string())),
+    }
+    Ok(())
+}
+
+async fn can_view_quote(db: &Pool<Postgres>, id: i32, user: &User) -> Result<bool, sqlx::Error> {
+    Ok(query!(
+        "SELECT q.id FROM quotes q
+        WHERE q.id = $1
+        AND CASE
+            WHEN $3 THEN TRUE
+            ELSE (CASE
+                WHEN q.id IN (SELECT quote_id FROM hidden) AND
+                (q.submitter=$2 OR $2 IN (
+                    SELECT speaker FROM shards
+                    WHERE quote_id=q.id))
+                THEN TRUE
+                ELSE q.id NOT IN (SELECT quote_id FROM hidden)
+            END)
+        END",
+        id,
+        user.preferred_username,
+        user.admin() || !*SECURITY_ENABLED,
+    )
+    .fetch_optional(db)
+    .await?
+    .is_some())
+}
+
+/// Queues a notification for the submitter and speakers of a quote, except
+/// for `skip`
+async fn notify_participants(
+    state: &AppState,
+    transaction: &mut Transaction<'_, Postgres>,
+    id: i32,
+    event: NotificationEvent,
+    skip: Option<&str>,
+    body: &str,
+) -> Result<(), sqlx::Error> {
+    let participants = query!(
+        "SELECT submitter AS \"username!\" FROM quotes WHERE id = $1
+        UNION
+        SELECT speaker FROM shards WHERE quote_id = $1",
+        id,
+    )
+    .fetch_all(&mut **transaction)
+    .await?;
+    for participant in participants {
+        if Some(participant.username.as_str()) == skip {
+            continue;
+        }
+        outbox::enqueue(transaction, state, event, &participant.username, body).await?;
+    }
+    Ok(())
+}
+
+/// The free text of a report, if any was given
+fn report_reason(report: &NewReport) -> Option<&str> {
+    report
+        .reason
+        .as_deref()
+        .map(str::trim)
+        .filter(|reason| !reason.is_empty())
+}
+
+/// Queues a notification for every moderator about a new report, unless
+/// they've already been told about unresolved reports on the same quote
+async fn notify_moderators(
+    transaction: &mut Transaction<'_, Postgres>,
+    id: i32,
+) -> Result<(), sqlx::Error> {
+    let alert = query!(
+        "INSERT INTO report_alerts (quote_id) VALUES ($1) ON CONFLICT DO NOTHING",
+        id
+    )
+    .execute(&mut **transaction)
+    .await?;
+    if alert.rows_affected() == 0 {
+        return Ok(());
+    }
+    outbox::enqueue_moderators(
+        transaction,
+        &format!("Quote #{id} was reported. Review it at Quotefault!"),
+    )
+    .await
+}
+
+/// Page size for listings: 10 by default, or everything for `-1`
+fn page_limit(limit: Option<i64>) -> i64 {
+    match limit {
+        Some(-1) => i64::MAX,
+        Some(limit) => limit.max(0),
+        None => 10,
+    }
+}
+
+fn reporter_hash(username: &str) -> Vec<u8> {
+    let mut hasher = Sha3_256::new();
+    hasher.update(format!("{}coleandethanwerehere", username).as_str()); // >:)
+    hasher.finalize().to_vec()
+}
+
+async fn comments_to_responses(
+    comments: &[Comment],
+    ldap: &ldap::client::LdapClient,
+) -> Result<Vec<CommentResponse>, HttpResponse> {
+    let uids = BTreeSet::from_iter(comments.iter().map(|x| x.author.clone()));
+    let uid_map: HashMap<String, String> =
+        match ldap::get_users(ldap, Vec::from_iter(uids).as_slice()).await {
+            Ok(users) => users.into_iter().map(|x| (x.uid, x.cn)).collect(),
+            Err(err) => return Err(HttpResponse::InternalServerError().body(err.to_string())),
+        };
+    Ok(comments
+        .iter()
+        .filter_map(|comment| {
+            Some(CommentResponse {
+                id: comment.id,
+                author: UserResponse {
+                    uid: comment.author.clone(),
+                    cn: uid_map.get(&comment.author)?.clone(),
+                },
+                body: comment.body.clone(),
+                timestamp: comment.timestamp,
+            })
+        })
+        .collect())
+}
+
+async fn user_counts_to_responses(
+    counts: &[UserCount],
+    ldap: &ldap::client::LdapClient,
+) -> Result<Vec<UserStatResponse>, SqlxErrorOrResponse<'static>> {
+    let uids: Vec<String> = counts.iter().map(|x| x.uid.clone()).collect();
+    let uid_map: HashMap<String, String> = match ldap::get_users(ldap, uids.as_slice()).await {
+        Ok(users) => users.into_iter().map(|x| (x.uid, x.cn)).collect(),
+        Err(err) => {
+            return Err(SqlxErrorOrResponse::ResponseOwned(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                err.to_string(),
+            ))
+        }
+    };
+    Ok(counts
+        .iter()
+        .map(|x| UserStatResponse {
+            user: UserResponse {
+                uid: x.uid.clone(),
+                // Fall back to the uid for users who have left LDAP, so lists
+                // don't come back short
+                cn: uid_map.get(&x.uid).unwrap_or(&x.uid).clone(),
+            },
+            count: x.count,
+        })
+        .collect())
+}
+
+/// Formats one chunk of an export. `first` is whether no quote has been
+/// written yet, and is returned updated for the next chunk.
+async fn export_chunk(
+    state: &AppState,
+    user: &User,
+    ids: &[i32],
+    format: ExportFormat,
+    mut first: bool,
+) -> Result<(String, bool), io::Error> {
+    let shards = match query_file_as!(
+        QuoteShard,
+        "queries/get_quote.sql",
+        ids,
+        user.preferred_username,
+        user.admin() || !*SECURITY_ENABLED,
+    )
+    .fetch_all(&state.db)
+    .await
+    {
+        Ok(shards) => shards,
+        Err(err) => {
+            log!(Level::Error, "Export failed: {}", err);
+            return Err(io::Error::other(err));
+        }
+    };
+    let quotes = match shards_to_quotes(shards.as_slice(), &state.ldap).await {
+        Ok(quotes) => quotes,
+        Err(_) => {
+            log!(Level::Error, "Export failed to resolve names");
+            return Err(io::Error::other("Failed to resolve names"));
+        }
+    };
+    let mut out = String::new();
+    for quote in &quotes {
+        out.push_str(&format.format_quote(quote, first));
+        first = false;
+    }
+    Ok((out, first))
+}
+
+fn validate_import(
+    quote: &ImportQuote,
+    existing: &BTreeSet<String>,
+    kevlar_users: &HashSet<String>,
+    now: chrono::NaiveDateTime,
+) -> Result<Vec<String>, String> {
+    if quote.shards.is_empty() {
+        return Err("No quote shards specified".to_string());
+    }
+    if quote.shards.len() > 6 {
+        return Err("Maximum of 6 shards exceeded.".to_string());
+    }
+    if quote
+        .shards
+        .iter()
+        .any(|shard| shard.body.trim().is_empty())
+    {
+        return Err("Quote shards cannot be empty.".to_string());
+    }
+    if quote.timestamp > now {
+        return Err("Timestamp is in the future.".to_string());
+    }
+    for uid in quote
+        .shards
+        .iter()
+        .map(|shard| &shard.speaker)
+        .chain([&quote.submitter])
+    {
+        if !existing.contains(uid) {
+            return Err(format!("User {uid} does not exist."));
+        }
+    }
+    for shard in &quote.shards {
+        if kevlar_users.contains(&shard.speaker) {
+            return Err(format!("{} cannot be quoted.", shard.speaker));
+        }
+    }
+    normalize_tags(&quote.tags).map_err(|_| "Invalid tags.".to_string())
+}
+
+fn normalize_tag(tag: &str) -> String {
+    tag.trim().to_lowercase()
+}
+
+fn normalize_tags(tags: &[String]) -> Result<Vec<String>, HttpResponse> {
+    if tags.len() > 10 {
+        return Err(HttpResponse::BadRequest().body("Maximum of 10 tags exceeded."));
+    }
+    let tags = BTreeSet::from_iter(tags.iter().map(|x| normalize_tag(x)));
+    if tags.iter().any(|x| !is_valid_tag(x)) {
+        return Err(HttpResponse::BadRequest().body("Invalid tag format specified."));
+    }
+    Ok(tags.into_iter().collect())
+}
+
+async fn shards_to_revisions(
+    shards: &[RevisionShard],
+    ldap: &ldap::client::LdapClient,
+) -> Result<Vec<QuoteRevisionResponse>, HttpResponse> {
+    let mut uid_map: HashMap<String, Option<String>> = HashMap::new();
+    shards.iter().for_each(|x| {
+        uid_map.insert(x.speaker.clone(), None);
+    });
+    match ldap::get_users(
+        ldap,
+        uid_map.keys().cloned().collect::<Vec<String>>().as_slice(),
+    )
+    .await
+    {
+        Ok(users) => users.into_iter().for_each(|x| {
+            let _ = uid_map.insert(x.uid, Some(x.cn));
+        }),
+        Err(err) => return Err(HttpResponse::InternalServerError().body(err.to_string())),
+    }
+
+    // Shards are kept even if their speaker has left LDAP so the diffs line up
+    type Version = (i32, chrono::NaiveDateTime, Vec<(i32, QuoteShardResponse)>);
+    let mut versions: Vec<Version> = Vec::new();
+    for shard in shards {
+        let speaker = UserResponse {
+            uid: shard.speaker.clone(),
+            cn: uid_map
+                .get(&shard.speaker)
+                .cloned()
+                .flatten()
+                .unwrap_or_else(|| shard.speaker.clone()),
+        };
+        let shard_response = QuoteShardResponse {
+            body: shard.body.clone(),
+            speaker,
+        };
+        match versions.last_mut() {
+            Some((revision, _, shards)) if *revision == shard.revision => {
+                shards.push((shard.index, shard_response))
+            }
+            _ => versions.push((
+                shard.revision,
+                shard.timestamp,
+                vec![(shard.index, shard_response)],
+            )),
+        }
+    }
+
+    let count = versions.len();
+    let mut revisions: Vec<QuoteRevisionResponse> = Vec::new();
+    let mut previous: Option<HashMap<i32, QuoteShardResponse>> = None;
+    for (position, (revision, timestamp, shards)) in versions.into_iter().enumerate() {
+        let current: HashMap<i32, QuoteShardResponse> = shards.iter().cloned().collect();
+        let changes = match &previous {
+            Some(previous) => previous
+                .keys()
+                .chain(current.keys())
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .filter_map(|index| {
+                    let before = previous.get(index);
+                    let after = current.get(index);
+                    let unchanged = matches!((before, after), (Some(b), Some(a))
+                        if b.body == a.body && b.speaker.uid == a.speaker.uid);
+                    (!unchanged).then(|| ShardChangeResponse {
+                        index: *index,
+                        before: before.cloned(),
+                        after: after.cloned(),
+                    })
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+        revisions.push(QuoteRevisionResponse {
+            revision,
+            timestamp,
+            current: position + 1 == count,
+            shards: shards.into_iter().map(|(_, shard)| shard).collect(),
+            changes,
+        });
+        previous = Some(current);
+    }
+    Ok(revisions)
+}
+
 fn format_reports(quotes: &[ReportedQuoteShard]) -> Vec<ReportedQuoteResponse> {
-    let mut reported_quotes: HashMap<i32, ReportedQuoteResponse> = HashMap::new();
+    let mut reported_quotes: Vec<ReportedQuoteResponse> = Vec::new();
     for quote in quotes {
-        match reported_quotes.get_mut(&quote.quote_id) {
-            Some(reported_quote) => reported_quote.reports.push(ReportResponse {
-                reason: quote.report_reason.clone(),
-                timestamp: quote.report_timestamp,
-                id: quote.report_id,
-            }),
-            None => {
-                let _ = reported_quotes.insert(
-                    quote.quote_id,
-                    ReportedQuoteResponse {
-                        quote_id: quote.quote_id,
-                        reports: vec![ReportResponse {
-                            timestamp: quote.report_timestamp,
-                            reason: quote.report_reason.clone(),
-                            id: quote.report_id,
-                        }],
-                    },
-                );
+        let report = ReportResponse {
+            category: quote.report_category,
+            reason: quote.report_reason.clone(),
+            timestamp: quote.report_timestamp,
+            id: quote.report_id,
+            comment_id: quote.report_comment_id,
+        };
+        match reported_quotes.last_mut() {
+            Some(reported_quote) if reported_quote.quote_id == quote.quote_id => {
+                reported_quote.reports.push(report)
             }
+            _ => reported_quotes.push(ReportedQuoteResponse {
+                quote_id: quote.quote_id,
+                reports: vec![report],
+            }),
         }
     }
-    reported_quotes.into_values().collect()
+    reported_quotes
 }
 
 impl ResponseError for SqlxErrorOrResponse<'_> {
@@ -182,10 +584,30 @@ pub enum SqlxErrorOrResponse<'a> {
     ResponseOwned(StatusCode, String),
 }
 
+/// Appends an entry to the moderation audit log
+pub async fn record_audit(
+    transaction: &mut Transaction<'_, Postgres>,
+    actor: &str,
+    action: AuditAction,
+    quote_id: Option<i32>,
+    details: Option<&str>,
+) -> Result<PgQueryResult, sqlx::Error> {
+    query!(
+        "INSERT INTO audit_log (actor, action, quote_id, details) VALUES ($1, $2, $3, $4)",
+        actor,
+        action as AuditAction,
+        quote_id,
+        details,
+    )
+    .execute(&mut **transaction)
+    .await
+}
+
 pub async fn hide_quote_by_id(
     id: i32,
     user: User,
     reason: String,
+    state: &AppState,
     transaction: &mut Transaction<'_, Postgres>,
 ) -> Result<(), SqlxErrorOrResponse<'static>> {
     let result = query!(
@@ -197,7 +619,7 @@ pub async fn hide_quote_by_id(
                     WHERE s.speaker = $3
                 ))",
         id,
-        reason,
+        reason.as_str(),
         user.preferred_username,
         user.admin() || !*SECURITY_ENABLED,
     )
@@ -210,10 +632,99 @@ pub async fn hide_quote_by_id(
         ))
     } else {
         log!(Level::Trace, "hid quote");
+        record_audit(
+            transaction,
+            &user.preferred_username,
+            AuditAction::Hide,
+            Some(id),
+            Some(&reason),
+        )
+        .await?;
+        let submitter = query!("SELECT submitter FROM quotes WHERE id = $1", id)
+            .fetch_one(&mut **transaction)
+            .await?
+            .submitter;
+        if submitter != user.preferred_username {
+            outbox::enqueue(
+                transaction,
+                state,
+                NotificationEvent::Moderated,
+                &submitter,
+                &format!("Your quote #{id} was hidden: {reason}"),
+            )
+            .await?;
+        }
+        Ok(())
+    }
+}
+
+pub async fn unhide_quote_by_id(
+    id: i32,
+    user: User,
+    reason: String,
+    transaction: &mut Transaction<'_, Postgres>,
+) -> Result<(), SqlxErrorOrResponse<'static>> {
+    let result = query!(
+        "WITH restored AS (
+            DELETE FROM public.hidden
+            WHERE quote_id = $1 AND ($4 OR actor = $3)
+            RETURNING quote_id, reason, actor, timestamp
+        )
+        INSERT INTO public.unhidden(quote_id, hidden_reason, hidden_actor, hidden_timestamp, reason, actor)
+            SELECT quote_id, reason, actor, timestamp, $2, $3::varchar
+            FROM restored",
+        id,
+        reason.as_str(),
+        user.preferred_username,
+        user.admin() || !*SECURITY_ENABLED,
+    )
+    .execute(&mut **transaction)
+    .await?;
+    if result.rows_affected() == 0 {
+        Err(SqlxErrorOrResponse::Response(
+            StatusCode::BAD_REQUEST,
+            "Either you did not hide this quote or this quote is not hidden.",
+        ))
+    } else {
+        log!(Level::Trace, "unhid quote");
+        record_audit(
+            transaction,
+            &user.preferred_username,
+            AuditAction::Unhide,
+            Some(id),
+            Some(&reason),
+        )
+        .await?;
         Ok(())
     }
 }
 
+pub async fn set_quote_tags(
+    id: i32,
+    tags: &[String],
+    transaction: &mut Transaction<'_, Postgres>,
+) -> Result<(), SqlxErrorOrResponse<'static>> {
+    query!("DELETE FROM quote_tags WHERE quote_id = $1", id)
+        .execute(&mut **transaction)
+        .await?;
+    query!(
+        "INSERT INTO tags (name) SELECT * FROM UNNEST($1::varchar[]) ON CONFLICT DO NOTHING",
+        tags
+    )
+    .execute(&mut **transaction)
+    .await?;
+    query!(
+        "INSERT INTO quote_tags (quote_id, tag_id)
+        SELECT $1, id FROM tags WHERE name = ANY($2)",
+        id,
+        tags
+    )
+    .execute(&mut **transaction)
+    .await?;
+    log!(Level::Trace, "set quote tags");
+    Ok(())
+}
+
 /// Creates a quote
 #[utoipa::path(
     post,
@@ -234,41 +745,13 @@ pub async fn create_quote(
 ) -> impl Responder {
     log!(Level::Info, "POST /api/quote");
 
-    if body.shards.is_empty() {
-        return HttpResponse::BadRequest().body("No quote shards specified");
-    }
-    if body.shards.len() > 6 {
-        return HttpResponse::BadRequest().body("Maximum of 6 shards exceeded.");
-    }
-    for shard in &body.shards {
-        if !is_valid_username(shard.speaker.as_str()) {
-            return HttpResponse::BadRequest().body("Invalid speaker username format specified.");
-        }
-        if user.preferred_username == shard.speaker {
-            return HttpResponse::BadRequest().body("Erm... maybe don't quote yourself?");
-        }
-    }
-    if !is_valid_username(user.preferred_username.as_str()) {
-        return HttpResponse::BadRequest()
-            .body("Invalid submitter username specified. SHOULD NEVER HAPPEN!");
+    if let Err(res) = validate_shards(&state, &body.shards, &user).await {
+        return res;
     }
-    let mut users: Vec<String> = body.shards.iter().map(|x| x.speaker.clone()).collect();
-    let kevlar = match any_user_has_kevlar(&state.db, users.as_slice()).await {
-        Ok(b) => b,
-        Err(e) => return HttpResponse::InternalServerError().body(e.to_string()),
+    let tags = match normalize_tags(body.tags.as_deref().unwrap_or_default()) {
+        Ok(tags) => tags,
+        Err(res) => return res,
     };
-    if kevlar {
-        return HttpResponse::BadRequest().body("One or more speakers cannot be quoted");
-    }
-    users.push(user.preferred_username.clone());
-    match ldap::users_exist(&state.ldap, BTreeSet::from_iter(users.into_iter())).await {
-        Ok(exists) => {
-            if !exists {
-                return HttpResponse::BadRequest().body("Some users submitted do not exist.");
-            }
-        }
-        Err(err) => return HttpResponse::InternalServerError().body(err.to_string()),
-    }
 
     let mut transaction = match open_transaction(&state.db).await {
         Ok(t) => t,
@@ -319,21 +802,29 @@ pub async fn create_quote(
 
     log!(Level::Trace, "created quote shards");
 
-    match transaction.commit().await {
-        Ok(_) => {
-            for shard in &body.shards {
-                if let Err(err) = send_ping(
-                    shard.speaker.clone(),
-                    format!(
-                        "You were quoted by {}. Check it out at Quotefault!",
-                        user.preferred_username
-                    ),
-                ) {
-                    log!(Level::Error, "Failed to ping: {}", err);
-                }
-            }
-            HttpResponse::Ok().body("")
+    if let Err(err) = set_quote_tags(id, &tags, &mut transaction).await {
+        return err.error_response();
+    }
+
+    for speaker in BTreeSet::from_iter(body.shards.iter().map(|x| x.speaker.clone())) {
+        if let Err(err) = outbox::enqueue(
+            &mut transaction,
+            &state,
+            NotificationEvent::Quoted,
+            &speaker,
+            &format!(
+                "You were quoted by {}. Check it out at Quotefault!",
+                user.preferred_username
+            ),
+        )
+        .await
+        {
+            return SqlxErrorOrResponse::from(err).error_response();
         }
+    }
+
+    match transaction.commit().await {
+        Ok(_) => HttpResponse::Ok().body(""),
         Err(e) => {
             log!(Level::Error, "Transaction failed to commit");
             HttpResponse::InternalServerError().body(e.to_string())
@@ -341,39 +832,140 @@ pub async fn create_quote(
     }
 }
 
-/// Deletes a quote
+/// Imports legacy quotes with their original submitters and timestamps
 #[utoipa::path(
-    delete,
-    path = "/api/quote/{id}",
-    params(
-        ("id" = i32, Path, description = "ID of quote to delete")
-    ),
+    post,
+    path = "/api/quotes/import",
+    request_body = Vec<ImportQuote>,
     responses(
-        (status = OK, description = "Quote deleted successfully"),
-        (status = BAD_REQUEST, description = "Either requester does not own the quote or the quote does not exist"),
-        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = OK, description = "Quotes imported successfully", body = ImportResponse),
+        (status = BAD_REQUEST, description = "One or more rows are invalid, nothing was imported", body = ImportResponse),
+        (status = UNAUTHORIZED, description = "Not authenticated or not an admin"),
         (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
     ),
 )]
-#[delete("/quote/{id}", wrap = "CSHAuth::enabled()")]
-pub async fn delete_quote(state: Data<AppState>, path: Path<(i32,)>, user: User) -> impl Responder {
-    let (id,) = path.into_inner();
+#[post("/quotes/import", wrap = "CSHAuth::admin_only()")]
+pub async fn import_quotes(
+    state: Data<AppState>,
+    body: Json<Vec<ImportQuote>>,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    if body.len() > 1000 {
+        return Err(SqlxErrorOrResponse::Response(
+            StatusCode::BAD_REQUEST,
+            "Maximum of 1000 quotes per import exceeded.",
+        ));
+    }
 
-    let mut transaction = match open_transaction(&state.db).await {
-        Ok(t) => t,
-        Err(res) => return res,
-    };
+    let usernames = BTreeSet::from_iter(
+        body.iter()
+            .flat_map(|quote| {
+                quote
+                    .shards
+                    .iter()
+                    .map(|shard| shard.speaker.clone())
+                    .chain([quote.submitter.clone()])
+            })
+            .filter(|uid| is_valid_username(uid)),
+    );
+    let existing: BTreeSet<String> =
+        match ldap::get_users(&state.ldap, Vec::from_iter(usernames).as_slice()).await {
+            Ok(users) => users.into_iter().map(|x| x.uid).collect(),
+            Err(err) => {
+                return Err(SqlxErrorOrResponse::ResponseOwned(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    err.to_string(),
+                ))
+            }
+        };
+    let kevlar_users = get_kevlar_users(&state.db).await?;
+    let now = chrono::Utc::now().naive_utc();
 
-    match log_query(
-        query!(
-            "DELETE FROM quotes WHERE id = $1 AND submitter = $2",
-            id,
-            user.preferred_username
+    let mut errors = Vec::new();
+    let mut rows = Vec::new();
+    for (row, quote) in body.iter().enumerate() {
+        match validate_import(quote, &existing, &kevlar_users, now) {
+            Ok(tags) => rows.push((quote, tags)),
+            Err(error) => errors.push(ImportErrorResponse { row, error }),
+        }
+    }
+    if !errors.is_empty() {
+        return Ok(HttpResponse::BadRequest().json(ImportResponse {
+            imported: Vec::new(),
+            errors,
+        }));
+    }
+
+    let mut transaction = state.db.begin().await?;
+    let mut imported = Vec::new();
+    for (quote, tags) in rows {
+        let id = query_as!(
+            ID,
+            "INSERT INTO quotes(submitter, timestamp) VALUES ($1, $2) RETURNING id",
+            quote.submitter,
+            quote.timestamp,
         )
-        .execute(&mut *transaction)
-        .await,
-        Some(transaction),
-    )
+        .fetch_one(&mut *transaction)
+        .await?
+        .id;
+
+        let ids: Vec<i32> = vec![id; quote.shards.len()];
+        let indices: Vec<i16> = (1..=quote.shards.len()).map(|a| a as i16).collect();
+        let bodies: Vec<String> = quote.shards.iter().map(|s| s.body.clone()).collect();
+        let speakers: Vec<String> = quote.shards.iter().map(|s| s.speaker.clone()).collect();
+        query!(
+            "INSERT INTO Shards (quote_id, index, body, speaker)
+            SELECT quote_id, index, body, speaker
+            FROM UNNEST($1::int4[], $2::int2[], $3::text[], $4::varchar[]) as a(quote_id, index, body, speaker)",
+            ids.as_slice(),
+            indices.as_slice(),
+            bodies.as_slice(),
+            speakers.as_slice()
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        set_quote_tags(id, &tags, &mut transaction).await?;
+        imported.push(id);
+    }
+    transaction.commit().await?;
+    log!(Level::Info, "imported {} quotes", imported.len());
+
+    Ok(HttpResponse::Ok().json(ImportResponse { imported, errors }))
+}
+
+/// Deletes a quote
+#[utoipa::path(
+    delete,
+    path = "/api/quote/{id}",
+    params(
+        ("id" = i32, Path, description = "ID of quote to delete")
+    ),
+    responses(
+        (status = OK, description = "Quote deleted successfully"),
+        (status = BAD_REQUEST, description = "Either requester does not own the quote or the quote does not exist"),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[delete("/quote/{id}", wrap = "CSHAuth::enabled()")]
+pub async fn delete_quote(state: Data<AppState>, path: Path<(i32,)>, user: User) -> impl Responder {
+    let (id,) = path.into_inner();
+
+    let mut transaction = match open_transaction(&state.db).await {
+        Ok(t) => t,
+        Err(res) => return res,
+    };
+
+    match log_query(
+        query!(
+            "DELETE FROM quotes WHERE id = $1 AND submitter = $2",
+            id,
+            user.preferred_username
+        )
+        .execute(&mut *transaction)
+        .await,
+        Some(transaction),
+    )
     .await
     {
         Ok((tx, result)) => {
@@ -388,6 +980,263 @@ pub async fn delete_quote(state: Data<AppState>, path: Path<(i32,)>, user: User)
 
     log!(Level::Trace, "deleted quote and all shards");
 
+    match log_query(
+        record_audit(
+            &mut transaction,
+            &user.preferred_username,
+            AuditAction::Delete,
+            Some(id),
+            None,
+        )
+        .await,
+        Some(transaction),
+    )
+    .await
+    {
+        Ok((tx, _)) => transaction = tx.unwrap(),
+        Err(res) => return res,
+    }
+
+    match transaction.commit().await {
+        Ok(_) => HttpResponse::Ok().body(""),
+        Err(e) => {
+            log!(Level::Error, "Transaction failed to commit");
+            HttpResponse::InternalServerError().body(e.to_string())
+        }
+    }
+}
+
+/// Removes any quote, leaving a tombstone with the reason
+#[utoipa::path(
+    put,
+    path = "/api/quote/{id}/remove",
+    params(
+        ("id" = i32, Path, description = "ID of quote to remove")
+    ),
+    request_body = Reason,
+    responses(
+        (status = OK, description = "Quote removed successfully"),
+        (status = BAD_REQUEST, description = "No reason was given or the quote does not exist"),
+        (status = UNAUTHORIZED, description = "Not an admin"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[put("/quote/{id}/remove", wrap = "CSHAuth::admin_only()")]
+pub async fn remove_quote(
+    state: Data<AppState>,
+    path: Path<(i32,)>,
+    user: User,
+    Json(reason): Json<Reason>,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    let (id,) = path.into_inner();
+
+    let reason = reason.reason.trim().to_string();
+    if reason.is_empty() {
+        return Err(SqlxErrorOrResponse::Response(
+            StatusCode::BAD_REQUEST,
+            "A reason is required to remove a quote.",
+        ));
+    }
+
+    state
+        .db
+        .acquire()
+        .await?
+        .transaction(|transaction| {
+            Box::pin(async move {
+                let Some(quote) =
+                    query!("DELETE FROM quotes WHERE id = $1 RETURNING submitter", id)
+                        .fetch_optional(&mut **transaction)
+                        .await?
+                else {
+                    return Err(SqlxErrorOrResponse::Response(
+                        StatusCode::BAD_REQUEST,
+                        "This quote does not exist.",
+                    ));
+                };
+                log!(Level::Trace, "removed quote and all shards");
+
+                query!(
+                    "INSERT INTO deleted_quotes (id, reason, actor) VALUES ($1, $2, $3)",
+                    id,
+                    reason,
+                    user.preferred_username,
+                )
+                .execute(&mut **transaction)
+                .await?;
+                record_audit(
+                    transaction,
+                    &user.preferred_username,
+                    AuditAction::Delete,
+                    Some(id),
+                    Some(&reason),
+                )
+                .await?;
+
+                if quote.submitter != user.preferred_username {
+                    outbox::enqueue(
+                        transaction,
+                        &state,
+                        NotificationEvent::Moderated,
+                        &quote.submitter,
+                        &format!("Your quote #{id} was removed by moderators: {reason}"),
+                    )
+                    .await?;
+                }
+                Ok(())
+            })
+        })
+        .await?;
+    Ok(HttpResponse::Ok().body(""))
+}
+
+/// Edits a quote, keeping the previous shards as a revision
+#[utoipa::path(
+    put,
+    path = "/api/quote/{id}",
+    params(
+        ("id" = i32, Path, description = "ID of quote to edit")
+    ),
+    request_body = NewQuote,
+    responses(
+        (status = OK, description = "Quote edited successfully"),
+        (status = BAD_REQUEST, description = "Malformed request, or either requester does not own the quote or the quote does not exist"),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[put("/quote/{id}", wrap = "CSHAuth::enabled()")]
+pub async fn edit_quote(
+    state: Data<AppState>,
+    path: Path<(i32,)>,
+    body: Json<NewQuote>,
+    user: User,
+) -> impl Responder {
+    let (id,) = path.into_inner();
+
+    if let Err(res) = validate_shards(&state, &body.shards, &user).await {
+        return res;
+    }
+    let tags = match body.tags.as_deref().map(normalize_tags).transpose() {
+        Ok(tags) => tags,
+        Err(res) => return res,
+    };
+
+    let mut transaction = match open_transaction(&state.db).await {
+        Ok(t) => t,
+        Err(res) => return res,
+    };
+
+    match log_query_as(
+        query_as!(
+            ID,
+            "SELECT id FROM quotes WHERE id = $1 AND submitter = $2 FOR UPDATE",
+            id,
+            user.preferred_username
+        )
+        .fetch_all(&mut *transaction)
+        .await,
+        Some(transaction),
+    )
+    .await
+    {
+        Ok((tx, ids)) => {
+            if ids.is_empty() {
+                return HttpResponse::BadRequest()
+                    .body("Either this is not your quote or this quote does not exist.");
+            }
+            transaction = tx.unwrap();
+        }
+        Err(res) => return res,
+    }
+
+    let previous_speakers: Vec<String>;
+    match log_query_as(
+        query!(
+            "INSERT INTO quote_revisions (quote_id, revision, index, body, speaker)
+            SELECT quote_id, (
+                SELECT COALESCE(MAX(revision), 0) + 1 FROM quote_revisions WHERE quote_id = $1
+            ), index, body, speaker
+            FROM shards
+            WHERE quote_id = $1
+            RETURNING speaker",
+            id
+        )
+        .fetch_all(&mut *transaction)
+        .await,
+        Some(transaction),
+    )
+    .await
+    {
+        Ok((tx, rows)) => {
+            transaction = tx.unwrap();
+            previous_speakers = rows.into_iter().map(|x| x.speaker).collect();
+        }
+        Err(res) => return res,
+    }
+    log!(Level::Trace, "archived previous quote shards");
+
+    match log_query(
+        query!("DELETE FROM shards WHERE quote_id = $1", id)
+            .execute(&mut *transaction)
+            .await,
+        Some(transaction),
+    )
+    .await
+    {
+        Ok((tx, _)) => transaction = tx.unwrap(),
+        Err(res) => return res,
+    }
+
+    let ids: Vec<i32> = vec![id; body.shards.len()];
+    let indices: Vec<i16> = (1..=body.shards.len()).map(|a| a as i16).collect();
+    let bodies: Vec<String> = body.shards.iter().map(|s| s.body.clone()).collect();
+    let speakers: Vec<String> = body.shards.iter().map(|s| s.speaker.clone()).collect();
+
+    match log_query(
+        query!(
+            "INSERT INTO Shards (quote_id, index, body, speaker)
+            SELECT quote_id, index, body, speaker
+            FROM UNNEST($1::int4[], $2::int2[], $3::text[], $4::varchar[]) as a(quote_id, index, body, speaker)",
+            ids.as_slice(),
+            indices.as_slice(),
+            bodies.as_slice(),
+            speakers.as_slice()
+        )
+        .execute(&mut *transaction)
+        .await, Some(transaction)).await {
+        Ok((tx, _)) => transaction = tx.unwrap(),
+        Err(res) => return res,
+    }
+
+    log!(Level::Trace, "replaced quote shards");
+
+    if let Some(tags) = tags {
+        if let Err(err) = set_quote_tags(id, &tags, &mut transaction).await {
+            return err.error_response();
+        }
+    }
+
+    for speaker in BTreeSet::from_iter(speakers) {
+        if previous_speakers.contains(&speaker) {
+            continue;
+        }
+        if let Err(err) = outbox::enqueue(
+            &mut transaction,
+            &state,
+            NotificationEvent::Quoted,
+            &speaker,
+            &format!(
+                "You were quoted by {}. Check it out at Quotefault!",
+                user.preferred_username
+            ),
+        )
+        .await
+        {
+            return SqlxErrorOrResponse::from(err).error_response();
+        }
+    }
+
     match transaction.commit().await {
         Ok(_) => HttpResponse::Ok().body(""),
         Err(e) => {
@@ -432,7 +1281,48 @@ pub async fn hide_quote(
         .acquire()
         .await?
         .transaction(|transaction| {
-            Box::pin(async move { hide_quote_by_id(id, user, reason, transaction).await })
+            Box::pin(async move { hide_quote_by_id(id, user, reason, &state, transaction).await })
+        })
+        .await?;
+    Ok(HttpResponse::Ok().body(""))
+}
+
+/// Unhides a quote
+#[utoipa::path(
+    put,
+    path = "/api/quote/{id}/unhide",
+    params(
+        ("id" = i32, Path, description = "ID of quote to unhide"),
+    ),
+    request_body = Reason,
+    responses(
+        (status = OK, description = "Quote unhidden successfully"),
+        (status = BAD_REQUEST, description = "Either requester did not hide the quote or the quote is not hidden"),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[put("/quote/{id}/unhide", wrap = "CSHAuth::enabled()")]
+pub async fn unhide_quote(
+    state: Data<AppState>,
+    path: Path<(i32,)>,
+    user: User,
+    Json(reason): Json<Reason>,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    let (id,) = path.into_inner();
+
+    let reason = if reason.reason.is_empty() {
+        "No reason given".to_string()
+    } else {
+        reason.reason
+    };
+
+    state
+        .db
+        .acquire()
+        .await?
+        .transaction(|transaction| {
+            Box::pin(async move { unhide_quote_by_id(id, user, reason, transaction).await })
         })
         .await?;
     Ok(HttpResponse::Ok().body(""))
@@ -445,6 +1335,7 @@ pub async fn hide_quote(
     params(
         ("id" = i32, Path, description = "ID of quote to report")
     ),
+    request_body = NewReport,
     responses(
         (status = OK, description = "Quote reported successfully"),
         (status = BAD_REQUEST, description = "Quote already reported by the same user or the quote doesn't exist"),
@@ -456,7 +1347,7 @@ pub async fn hide_quote(
 pub async fn report_quote(
     state: Data<AppState>,
     path: Path<(i32,)>,
-    body: Json<Reason>,
+    body: Json<NewReport>,
     user: User,
 ) -> impl Responder {
     let (id,) = path.into_inner();
@@ -466,21 +1357,20 @@ pub async fn report_quote(
         Err(res) => return res,
     };
 
-    let mut hasher = Sha3_256::new();
-    hasher.update(format!("{}coleandethanwerehere", user.preferred_username).as_str()); // >:)
-    let result = hasher.finalize();
+    let result = reporter_hash(&user.preferred_username);
 
     match log_query(
         query!(
-            "INSERT INTO reports (quote_id, reason, submitter_hash)
-            SELECT $1, $2, $3
+            "INSERT INTO reports (quote_id, category, reason, submitter_hash)
+            SELECT $1, $2, $3, $4
             WHERE $1 IN (
                 SELECT id FROM quotes
                 WHERE id NOT IN (SELECT quote_id FROM hidden)
             )
             ON CONFLICT DO NOTHING",
             id,
-            body.reason,
+            body.category as ReportCategory,
+            report_reason(&body),
             result.as_slice()
         )
         .execute(&mut *transaction)
@@ -500,6 +1390,38 @@ pub async fn report_quote(
     };
     log!(Level::Trace, "created a new report");
 
+    if let Err(err) = notify_moderators(&mut transaction, id).await {
+        return SqlxErrorOrResponse::from(err).error_response();
+    }
+
+    // The submitter and speakers hear about the first open report on their
+    // quote, never who filed it
+    let first_report = match query!(
+        "SELECT COUNT(*) AS \"count!\" FROM reports
+        WHERE quote_id = $1 AND comment_id IS NULL AND resolver IS NULL",
+        id
+    )
+    .fetch_one(&mut *transaction)
+    .await
+    {
+        Ok(open) => open.count == 1,
+        Err(err) => return SqlxErrorOrResponse::from(err).error_response(),
+    };
+    if first_report {
+        if let Err(err) = notify_participants(
+            &state,
+            &mut transaction,
+            id,
+            NotificationEvent::Moderated,
+            None,
+            &format!("Your quote #{id} was reported and is waiting for review."),
+        )
+        .await
+        {
+            return SqlxErrorOrResponse::from(err).error_response();
+        }
+    }
+
     match transaction.commit().await {
         Ok(_) => HttpResponse::Ok().body(""),
         Err(e) => {
@@ -519,6 +1441,7 @@ pub async fn report_quote(
     responses(
         (status = OK, description = "Quote reported successfully", body = QuoteResponse),
         (status = NOT_FOUND, description = "Quote not found"),
+        (status = GONE, description = "Quote was removed by moderators"),
         (status = UNAUTHORIZED, description = "Not authenticated"),
         (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
     ),
@@ -528,55 +1451,10 @@ pub async fn get_quote(state: Data<AppState>, path: Path<(i32,)>, user: User) ->
     let (id,) = path.into_inner();
 
     match log_query_as(
-        query_as!(
+        query_file_as!(
             QuoteShard,
-            "SELECT pq.id as \"id!\", s.index as \"index!\", pq.submitter as \"submitter!\",
-            pq.timestamp as \"timestamp!\", s.body as \"body!\", s.speaker as \"speaker!\",
-            hidden.reason as \"hidden_reason: Option<String>\", hidden.actor as \"hidden_actor: Option<String>\", 
-            v.vote as \"vote: Option<Vote>\",
-            (CASE WHEN t.score IS NULL THEN 0 ELSE t.score END) AS \"score!\",
-            (CASE WHEN f.username IS NULL THEN FALSE ELSE TRUE END) AS \"favorited!\"
-            FROM (
-                SELECT * FROM quotes q
-                WHERE q.id = $1
-                AND CASE
-                    WHEN $3 THEN TRUE
-                    ELSE (CASE
-                        WHEN q.id IN (SELECT quote_id FROM hidden) AND
-                        (q.submitter=$2 OR $2 IN (
-                            SELECT speaker FROM shards
-                            WHERE quote_id=q.id))
-                        THEN TRUE
-                        ELSE q.id NOT IN (SELECT quote_id FROM hidden)
-                    END)
-                END
-                ORDER BY q.id DESC
-            ) AS pq
-            LEFT JOIN hidden ON hidden.quote_id = pq.id
-            LEFT JOIN shards s ON s.quote_id = pq.id
-            LEFT JOIN (
-                SELECT quote_id, vote FROM votes
-                WHERE submitter=$2
-            ) v ON v.quote_id = pq.id
-            LEFT JOIN (
-                SELECT
-                    quote_id,
-                    SUM(
-                        CASE
-                            WHEN vote='upvote' THEN 1 
-                            WHEN vote='downvote' THEN -1
-                            ELSE 0
-                        END
-                    ) AS score
-                FROM votes
-                GROUP BY quote_id
-            ) t ON t.quote_id = pq.id
-            LEFT JOIN (
-                SELECT quote_id, username FROM favorites
-                WHERE username=$2
-            ) f ON f.quote_id = pq.id
-            ORDER BY timestamp DESC, pq.id DESC, s.index",
-            id,
+            "queries/get_quote.sql",
+            &[id],
             user.preferred_username,
             user.admin() || !*SECURITY_ENABLED,
         )
@@ -588,7 +1466,16 @@ pub async fn get_quote(state: Data<AppState>, path: Path<(i32,)>, user: User) ->
     {
         Ok((_, shards)) => {
             if shards.is_empty() {
-                HttpResponse::NotFound().body("Quote could not be found")
+                match query!("SELECT id FROM deleted_quotes WHERE id = $1", id)
+                    .fetch_optional(&state.db)
+                    .await
+                {
+                    Ok(Some(_)) => {
+                        HttpResponse::Gone().body("This quote was removed by moderators.")
+                    }
+                    Ok(None) => HttpResponse::NotFound().body("Quote could not be found"),
+                    Err(e) => HttpResponse::InternalServerError().body(e.to_string()),
+                }
             } else {
                 match shards_to_quotes(shards.as_slice(), &state.ldap).await {
                     Ok(quotes) => HttpResponse::Ok().json(quotes.first().unwrap()),
@@ -600,30 +1487,433 @@ pub async fn get_quote(state: Data<AppState>, path: Path<(i32,)>, user: User) ->
     }
 }
 
-/// Adds a vote (upvote or downvote) to a quote
+/// Gets the quote of the day
+#[utoipa::path(
+    get,
+    path = "/api/quote/daily",
+    responses(
+        (status = OK, description = "Successfully fetched the quote of the day", body = QuoteResponse),
+        (status = NOT_FOUND, description = "No quote available"),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[get("/quote/daily", wrap = "CSHAuth::enabled()")]
+pub async fn get_daily_quote(
+    state: Data<AppState>,
+    user: User,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    let kevlar_users: Vec<String> = get_kevlar_users(&state.db).await?.into_iter().collect();
+
+    // The first pick of the day is stored so that votes during the day can't
+    // change it. It's only re-picked if that quote has since been hidden or
+    // someone on it has turned on kevlar
+    let stored = query_as!(
+        ID,
+        "SELECT d.quote_id AS id FROM daily_quotes d
+        WHERE d.date = CURRENT_DATE
+        AND d.quote_id NOT IN (SELECT quote_id FROM hidden)
+        AND NOT EXISTS (
+            SELECT 1 FROM quotes q WHERE q.id = d.quote_id AND q.submitter = ANY($1)
+        )
+        AND NOT EXISTS (
+            SELECT 1 FROM shards s WHERE s.quote_id = d.quote_id AND s.speaker = ANY($1)
+        )",
+        kevlar_users.as_slice(),
+    )
+    .fetch_optional(&state.db)
+    .await?;
+
+    let daily = match stored {
+        Some(stored) => Some(stored),
+        None => {
+            // Shuffle by a hash of the date so every replica agrees on the pick
+            let picked = query_as!(
+                ID,
+                "WITH eligible AS (
+                    SELECT q.id, COALESCE((
+                        SELECT SUM(
+                            CASE
+                                WHEN v.vote='upvote' THEN 1
+                                WHEN v.vote='downvote' THEN -1
+                                ELSE 0
+                            END
+                        ) FROM votes v
+                        WHERE v.quote_id = q.id
+                    ), 0) AS score
+                    FROM quotes q
+                    WHERE q.id NOT IN (SELECT quote_id FROM hidden)
+                    AND q.timestamp < CURRENT_DATE
+                    AND q.submitter <> ALL($1)
+                    AND NOT EXISTS (SELECT 1 FROM shards s WHERE s.quote_id = q.id AND s.speaker = ANY($1))
+                )
+                SELECT id FROM eligible
+                WHERE score > 0 OR NOT EXISTS (SELECT 1 FROM eligible WHERE score > 0)
+                ORDER BY md5(id::text || CURRENT_DATE::text), id
+                LIMIT 1",
+                kevlar_users.as_slice(),
+            )
+            .fetch_optional(&state.db)
+            .await?;
+
+            if let Some(picked) = &picked {
+                query!(
+                    "INSERT INTO daily_quotes (date, quote_id) VALUES (CURRENT_DATE, $1)
+                    ON CONFLICT (date) DO UPDATE SET quote_id = EXCLUDED.quote_id",
+                    picked.id,
+                )
+                .execute(&state.db)
+                .await?;
+            }
+            picked
+        }
+    };
+
+    let Some(daily) = daily else {
+        return Err(SqlxErrorOrResponse::Response(
+            StatusCode::NOT_FOUND,
+            "No quote available",
+        ));
+    };
+
+    let shards = query_file_as!(
+        QuoteShard,
+        "queries/get_quote.sql",
+        &[daily.id],
+        user.preferred_username,
+        false,
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    match shards_to_quotes(shards.as_slice(), &state.ldap).await {
+        Ok(quotes) => match quotes.first() {
+            Some(quote) => Ok(HttpResponse::Ok().json(quote)),
+            None => Err(SqlxErrorOrResponse::Response(
+                StatusCode::NOT_FOUND,
+                "No quote available",
+            )),
+        },
+        Err(res) => Ok(res),
+    }
+}
+
+/// Gets a random quote
+#[utoipa::path(
+    get,
+    path = "/api/quote/random",
+    params(RandomParams),
+    responses(
+        (status = OK, description = "Successfully fetched a random quote", body = QuoteResponse),
+        (status = NOT_FOUND, description = "No quote matches the filters"),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[get("/quote/random", wrap = "CSHAuth::enabled()")]
+pub async fn get_random_quote(
+    state: Data<AppState>,
+    params: web::Query<RandomParams>,
+    user: User,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    // Picks a random ID and walks the primary key from there to the first
+    // matching quote, so only the quotes between the pivot and the match are
+    // scanned
+    let kevlar_users: Vec<String> = get_kevlar_users(&state.db).await?.into_iter().collect();
+    let query = params.q.clone().unwrap_or_default();
+    let speaker = params.speaker.clone().unwrap_or("%".to_string());
+    let submitter = params.submitter.clone().unwrap_or("%".to_string());
+    let involved = params.involved.clone().unwrap_or("%".to_string());
+    let tag = params.tag.as_deref().map(normalize_tag);
+    let random = query_file_as!(
+        ID,
+        "queries/get_random_quote_id.sql",
+        query,                   // $1
+        speaker,                 // $2
+        submitter,               // $3
+        involved,                // $4
+        tag,                     // $5
+        params.min_score,        // $6
+        kevlar_users.as_slice(), // $7
+    )
+    .fetch_optional(&state.db)
+    .await?;
+
+    let Some(random) = random else {
+        return Err(SqlxErrorOrResponse::Response(
+            StatusCode::NOT_FOUND,
+            "No quote matches the filters",
+        ));
+    };
+
+    let shards = query_file_as!(
+        QuoteShard,
+        "queries/get_quote.sql",
+        &[random.id],
+        user.preferred_username,
+        false,
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    match shards_to_quotes(shards.as_slice(), &state.ldap).await {
+        Ok(quotes) => match quotes.first() {
+            Some(quote) => Ok(HttpResponse::Ok().json(quote)),
+            None => Err(SqlxErrorOrResponse::Response(
+                StatusCode::NOT_FOUND,
+                "No quote matches the filters",
+            )),
+        },
+        Err(res) => Ok(res),
+    }
+}
+
+/// Exports every quote the user said or submitted
+#[utoipa::path(
+    get,
+    path = "/api/me/export",
+    params(ExportParams),
+    responses(
+        (status = OK, description = "Streaming the export"),
+        (status = BAD_REQUEST, description = "Unknown export format"),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[get("/me/export", wrap = "CSHAuth::enabled()")]
+pub async fn export_quotes(
+    state: Data<AppState>,
+    params: web::Query<ExportParams>,
+    user: User,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    let Some(format) = ExportFormat::parse(params.format.as_deref().unwrap_or("json")) else {
+        return Err(SqlxErrorOrResponse::Response(
+            StatusCode::BAD_REQUEST,
+            "Format must be one of json, csv or markdown.",
+        ));
+    };
+
+    let ids: Vec<i32> = query_as!(
+        ID,
+        "SELECT q.id FROM quotes q
+        WHERE q.submitter = $1
+        OR q.id IN (SELECT quote_id FROM shards WHERE speaker = $1)
+        ORDER BY q.timestamp DESC, q.id DESC",
+        user.preferred_username,
+    )
+    .fetch_all(&state.db)
+    .await?
+    .into_iter()
+    .map(|x| x.id)
+    .collect();
+
+    let content_type = format.content_type();
+    let filename = format!("quotes.{}", format.extension());
+    let footer = format.footer();
+    let mut chunks = ids
+        .chunks(50)
+        .map(|x| x.to_vec())
+        .collect::<Vec<_>>()
+        .into_iter();
+
+    // The first chunk is formatted before the response starts, so a failing
+    // export is a 500 rather than a truncated download
+    let (head, first) = match chunks.next() {
+        Some(chunk) => export_chunk(&state, &user, &chunk, format, true)
+            .await
+            .map_err(|err| {
+                SqlxErrorOrResponse::ResponseOwned(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    err.to_string(),
+                )
+            })?,
+        None => (String::new(), true),
+    };
+
+    // The rest is fetched and formatted 50 at a time as the client reads
+    let body = futures::stream::unfold(
+        (chunks, first, false),
+        move |(mut chunks, first, finished)| {
+            let state = state.clone();
+            let user = user.clone();
+            let footer = footer.clone();
+            async move {
+                if finished {
+                    return None;
+                }
+                let Some(chunk) = chunks.next() else {
+                    return Some((Ok(Bytes::from(footer)), (chunks, first, true)));
+                };
+                match export_chunk(&state, &user, &chunk, format, first).await {
+                    Ok((out, first)) => Some((Ok(Bytes::from(out)), (chunks, first, false))),
+                    Err(err) => Some((Err(err), (chunks, first, true))),
+                }
+            }
+        },
+    );
+
+    Ok(HttpResponse::Ok()
+        .content_type(content_type)
+        .insert_header((
+            "Content-Disposition",
+            format!("attachment; filename=\"{filename}\""),
+        ))
+        .streaming(
+            futures::stream::once(async move { Ok(Bytes::from(format.header() + &head)) })
+                .chain(body),
+        ))
+}
+
+/// Gets every version of a quote, with what changed in each
+#[utoipa::path(
+    get,
+    path = "/api/quote/{id}/history",
+    params(
+        ("id" = i32, Path, description = "ID of quote")
+    ),
+    responses(
+        (status = OK, description = "Successfully fetched quote history", body = Vec<QuoteRevisionResponse>),
+        (status = NOT_FOUND, description = "Quote not found"),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[get("/quote/{id}/history", wrap = "CSHAuth::enabled()")]
+pub async fn get_quote_history(
+    state: Data<AppState>,
+    path: Path<(i32,)>,
+    user: User,
+) -> impl Responder {
+    let (id,) = path.into_inner();
+
+    match can_view_quote(&state.db, id, &user).await {
+        Ok(true) => {}
+        Ok(false) => return HttpResponse::NotFound().body("Quote could not be found"),
+        Err(e) => return HttpResponse::InternalServerError().body(e.to_string()),
+    }
+
+    match log_query_as(
+        query_as!(
+            RevisionShard,
+            // Revisions are archived when they're replaced, so each version was
+            // written when the one before it was archived, or when the quote
+            // was submitted for the first version
+            "SELECT revision AS \"revision!\", index AS \"index!\", body AS \"body!\",
+                speaker AS \"speaker!\", timestamp AS \"timestamp!\"
+            FROM (
+                SELECT r.revision, r.index::int4, r.body, r.speaker,
+                    COALESCE((
+                        SELECT MAX(p.timestamp) FROM quote_revisions p
+                        WHERE p.quote_id = r.quote_id AND p.revision = r.revision - 1
+                    ), q.timestamp) AS timestamp
+                FROM quote_revisions r
+                JOIN quotes q ON q.id = r.quote_id
+                WHERE r.quote_id = $1
+                UNION ALL
+                SELECT
+                    COALESCE((SELECT MAX(revision) FROM quote_revisions WHERE quote_id = $1), 0) + 1,
+                    s.index::int4, s.body, s.speaker,
+                    COALESCE((
+                        SELECT MAX(timestamp) FROM quote_revisions WHERE quote_id = $1
+                    ), q.timestamp)
+                FROM shards s
+                JOIN quotes q ON q.id = s.quote_id
+                WHERE s.quote_id = $1
+            ) AS versions
+            ORDER BY revision, index",
+            id,
+        )
+        .fetch_all(&state.db)
+        .await,
+        None,
+    )
+    .await
+    {
+        Ok((_, shards)) => match shards_to_revisions(shards.as_slice(), &state.ldap).await {
+            Ok(revisions) => HttpResponse::Ok().json(revisions),
+            Err(res) => res,
+        },
+        Err(res) => res,
+    }
+}
+
+/// Gets the comments on a quote
+#[utoipa::path(
+    get,
+    path = "/api/quote/{id}/comments",
+    params(
+        ("id" = i32, Path, description = "ID of quote")
+    ),
+    responses(
+        (status = OK, description = "Successfully fetched comments", body = Vec<CommentResponse>),
+        (status = NOT_FOUND, description = "Quote not found"),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[get("/quote/{id}/comments", wrap = "CSHAuth::enabled()")]
+pub async fn get_comments(state: Data<AppState>, path: Path<(i32,)>, user: User) -> impl Responder {
+    let (id,) = path.into_inner();
+
+    match can_view_quote(&state.db, id, &user).await {
+        Ok(true) => {}
+        Ok(false) => return HttpResponse::NotFound().body("Quote could not be found"),
+        Err(e) => return HttpResponse::InternalServerError().body(e.to_string()),
+    }
+
+    match log_query_as(
+        query_as!(
+            Comment,
+            "SELECT id, author, body, timestamp FROM comments
+            WHERE quote_id = $1
+            ORDER BY timestamp, id",
+            id,
+        )
+        .fetch_all(&state.db)
+        .await,
+        None,
+    )
+    .await
+    {
+        Ok((_, comments)) => match comments_to_responses(comments.as_slice(), &state.ldap).await {
+            Ok(comments) => HttpResponse::Ok().json(comments),
+            Err(res) => res,
+        },
+        Err(res) => res,
+    }
+}
+
+/// Comments on a quote
 #[utoipa::path(
     post,
-    path = "/api/quote/{id}/vote",
+    path = "/api/quote/{id}/comments",
     params(
-        ("id" = i32, Path, description = "ID of quote to vote on"),
-        VoteParams
+        ("id" = i32, Path, description = "ID of quote to comment on")
     ),
+    request_body = NewComment,
     responses(
-        (status = OK, description = "Quote voted on successfully"),
+        (status = OK, description = "Comment created successfully"),
+        (status = BAD_REQUEST, description = "Malformed comment"),
         (status = NOT_FOUND, description = "Quote not found"),
         (status = UNAUTHORIZED, description = "Not authenticated"),
         (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
     ),
 )]
-#[post("/quote/{id}/vote", wrap = "CSHAuth::enabled()")]
-pub async fn vote_quote(
+#[post("/quote/{id}/comments", wrap = "CSHAuth::enabled()")]
+pub async fn create_comment(
     state: Data<AppState>,
     path: Path<(i32,)>,
-    params: web::Query<VoteParams>,
+    body: Json<NewComment>,
     user: User,
 ) -> impl Responder {
     let (id,) = path.into_inner();
-    let vote = params.vote.clone();
+
+    if body.body.trim().is_empty() {
+        return HttpResponse::BadRequest().body("Comment cannot be empty.");
+    }
+    if body.body.chars().count() > 1000 {
+        return HttpResponse::BadRequest().body("Maximum comment length of 1000 exceeded.");
+    }
 
     let mut transaction = match open_transaction(&state.db).await {
         Ok(t) => t,
@@ -632,17 +1922,15 @@ pub async fn vote_quote(
 
     match log_query(
         query!(
-            "INSERT INTO votes (quote_id, vote, submitter)
+            "INSERT INTO comments (quote_id, author, body)
             SELECT $1, $2, $3
             WHERE $1 IN (
                 SELECT id FROM quotes
                 WHERE CASE WHEN $4 THEN true ELSE id NOT IN (SELECT quote_id FROM hidden) END
-            )
-            ON CONFLICT (quote_id, submitter)
-            DO UPDATE SET vote=$2",
+            )",
             id,
-            vote as Vote,
             user.preferred_username,
+            body.body,
             user.admin() || !*SECURITY_ENABLED
         )
         .execute(&mut *transaction)
@@ -659,6 +1947,23 @@ pub async fn vote_quote(
         }
         Err(res) => return res,
     }
+    log!(Level::Trace, "created a new comment");
+
+    if let Err(err) = notify_participants(
+        &state,
+        &mut transaction,
+        id,
+        NotificationEvent::Commented,
+        Some(&user.preferred_username),
+        &format!(
+            "{} commented on quote #{id}. Check it out at Quotefault!",
+            user.preferred_username
+        ),
+    )
+    .await
+    {
+        return SqlxErrorOrResponse::from(err).error_response();
+    }
 
     match transaction.commit().await {
         Ok(_) => HttpResponse::Ok().body(""),
@@ -669,23 +1974,28 @@ pub async fn vote_quote(
     }
 }
 
-/// Removes a vote from a quote
+/// Deletes a comment
 #[utoipa::path(
     delete,
-    path = "/api/quote/{id}/vote",
+    path = "/api/quote/{id}/comments/{comment_id}",
     params(
-        ("id" = i32, Path, description = "ID of quote to unvote")
+        ("id" = i32, Path, description = "ID of quote"),
+        ("comment_id" = i32, Path, description = "ID of comment to delete")
     ),
     responses(
-        (status = OK, description = "Removed vote from quote successfully"),
-        (status = NOT_FOUND, description = "Quote not found"),
+        (status = OK, description = "Comment deleted successfully"),
+        (status = BAD_REQUEST, description = "Either requester did not write the comment or the comment does not exist"),
         (status = UNAUTHORIZED, description = "Not authenticated"),
         (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
     ),
 )]
-#[delete("/quote/{id}/vote", wrap = "CSHAuth::enabled()")]
-pub async fn unvote_quote(state: Data<AppState>, path: Path<(i32,)>, user: User) -> impl Responder {
-    let (id,) = path.into_inner();
+#[delete("/quote/{id}/comments/{comment_id}", wrap = "CSHAuth::enabled()")]
+pub async fn delete_comment(
+    state: Data<AppState>,
+    path: Path<(i32, i32)>,
+    user: User,
+) -> impl Responder {
+    let (id, comment_id) = path.into_inner();
 
     let mut transaction = match open_transaction(&state.db).await {
         Ok(t) => t,
@@ -694,12 +2004,8 @@ pub async fn unvote_quote(state: Data<AppState>, path: Path<(i32,)>, user: User)
 
     match log_query(
         query!(
-            "DELETE FROM votes 
-            WHERE quote_id=$1 AND submitter=$2
-            AND $1 IN (
-                SELECT id FROM quotes
-                WHERE CASE WHEN $3 THEN true ELSE id NOT IN (SELECT quote_id FROM hidden) END
-            )",
+            "DELETE FROM comments WHERE id = $1 AND quote_id = $2 AND (author = $3 OR $4)",
+            comment_id,
             id,
             user.preferred_username,
             user.admin() || !*SECURITY_ENABLED
@@ -711,87 +2017,1221 @@ pub async fn unvote_quote(state: Data<AppState>, path: Path<(i32,)>, user: User)
     .await
     {
         Ok((tx, result)) => {
-            transaction = tx.unwrap();
             if result.rows_affected() == 0 {
-                return HttpResponse::NotFound().body("Quote does not exist");
+                return HttpResponse::BadRequest()
+                    .body("Either this is not your comment or this comment does not exist.");
             }
+            transaction = tx.unwrap()
         }
         Err(res) => return res,
     }
+    log!(Level::Trace, "deleted comment");
+
+    match log_query(
+        query!(
+            "DELETE FROM reports WHERE comment_id = $1 AND resolver IS NULL",
+            comment_id
+        )
+        .execute(&mut *transaction)
+        .await,
+        Some(transaction),
+    )
+    .await
+    {
+        Ok((tx, _)) => transaction = tx.unwrap(),
+        Err(res) => return res,
+    }
+
+    match transaction.commit().await {
+        Ok(_) => HttpResponse::Ok().body(""),
+        Err(e) => {
+            log!(Level::Error, "Transaction failed to commit");
+            HttpResponse::InternalServerError().body(e.to_string())
+        }
+    }
+}
+
+/// Reports a comment
+#[utoipa::path(
+    post,
+    path = "/api/quote/{id}/comments/{comment_id}/report",
+    params(
+        ("id" = i32, Path, description = "ID of quote"),
+        ("comment_id" = i32, Path, description = "ID of comment to report")
+    ),
+    request_body = NewReport,
+    responses(
+        (status = OK, description = "Comment reported successfully"),
+        (status = BAD_REQUEST, description = "Comment already reported by the same user or the comment doesn't exist"),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[post(
+    "/quote/{id}/comments/{comment_id}/report",
+    wrap = "CSHAuth::enabled()"
+)]
+pub async fn report_comment(
+    state: Data<AppState>,
+    path: Path<(i32, i32)>,
+    body: Json<NewReport>,
+    user: User,
+) -> impl Responder {
+    let (id, comment_id) = path.into_inner();
+
+    let mut transaction = match open_transaction(&state.db).await {
+        Ok(t) => t,
+        Err(res) => return res,
+    };
+
+    let result = reporter_hash(&user.preferred_username);
+
+    match log_query(
+        query!(
+            "INSERT INTO reports (quote_id, comment_id, category, reason, submitter_hash)
+            SELECT quote_id, id, $3, $4, $5 FROM comments
+            WHERE id = $2 AND quote_id = $1
+                AND quote_id NOT IN (SELECT quote_id FROM hidden)
+            ON CONFLICT DO NOTHING",
+            id,
+            comment_id,
+            body.category as ReportCategory,
+            report_reason(&body),
+            result.as_slice()
+        )
+        .execute(&mut *transaction)
+        .await,
+        Some(transaction),
+    )
+    .await
+    {
+        Ok((tx, result)) => {
+            transaction = tx.unwrap();
+            if result.rows_affected() == 0 {
+                return HttpResponse::BadRequest()
+                    .body("You have already reported this comment or comment does not exist");
+            }
+        }
+        Err(res) => return res,
+    };
+    log!(Level::Trace, "created a new comment report");
+
+    if let Err(err) = notify_moderators(&mut transaction, id).await {
+        return SqlxErrorOrResponse::from(err).error_response();
+    }
+
+    match transaction.commit().await {
+        Ok(_) => HttpResponse::Ok().body(""),
+        Err(e) => {
+            log!(Level::Error, "Transaction failed to commit");
+            HttpResponse::InternalServerError().body(e.to_string())
+        }
+    }
+}
+
+/// Adds a vote (upvote or downvote) to a quote
+#[utoipa::path(
+    post,
+    path = "/api/quote/{id}/vote",
+    params(
+        ("id" = i32, Path, description = "ID of quote to vote on"),
+        VoteParams
+    ),
+    responses(
+        (status = OK, description = "Quote voted on successfully"),
+        (status = NOT_FOUND, description = "Quote not found"),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[post("/quote/{id}/vote", wrap = "CSHAuth::enabled()")]
+pub async fn vote_quote(
+    state: Data<AppState>,
+    path: Path<(i32,)>,
+    params: web::Query<VoteParams>,
+    user: User,
+) -> impl Responder {
+    let (id,) = path.into_inner();
+    let vote = params.vote.clone();
+
+    let mut transaction = match open_transaction(&state.db).await {
+        Ok(t) => t,
+        Err(res) => return res,
+    };
+
+    let upvotes_before = match query!(
+        "SELECT COUNT(*) AS \"count!\" FROM votes WHERE quote_id = $1 AND vote = 'upvote'",
+        id
+    )
+    .fetch_one(&mut *transaction)
+    .await
+    {
+        Ok(upvotes) => upvotes.count,
+        Err(err) => return SqlxErrorOrResponse::from(err).error_response(),
+    };
+
+    match log_query(
+        query!(
+            "INSERT INTO votes (quote_id, vote, submitter)
+            SELECT $1, $2, $3
+            WHERE $1 IN (
+                SELECT id FROM quotes
+                WHERE CASE WHEN $4 THEN true ELSE id NOT IN (SELECT quote_id FROM hidden) END
+            )
+            ON CONFLICT (quote_id, submitter)
+            DO UPDATE SET vote=$2",
+            id,
+            vote as Vote,
+            user.preferred_username,
+            user.admin() || !*SECURITY_ENABLED
+        )
+        .execute(&mut *transaction)
+        .await,
+        Some(transaction),
+    )
+    .await
+    {
+        Ok((tx, result)) => {
+            transaction = tx.unwrap();
+            if result.rows_affected() == 0 {
+                return HttpResponse::NotFound().body("Quote does not exist");
+            }
+        }
+        Err(res) => return res,
+    }
+
+    // Milestones are only ever recorded once, so flipping a vote back and
+    // forth across one won't notify again. Milestones the quote had already
+    // passed before this vote, e.g. from before they were configured, are
+    // recorded without notifying anyone
+    let milestones: Vec<i32>;
+    match log_query_as(
+        query!(
+            "INSERT INTO score_milestones (quote_id, milestone)
+            SELECT $1, m.milestone FROM UNNEST($2::int4[]) AS m(milestone)
+            WHERE m.milestone <= (
+                SELECT COUNT(*) FROM votes
+                WHERE quote_id = $1 AND vote = 'upvote'
+            )
+            ON CONFLICT DO NOTHING
+            RETURNING milestone",
+            id,
+            SCORE_MILESTONES.as_slice(),
+        )
+        .fetch_all(&mut *transaction)
+        .await,
+        Some(transaction),
+    )
+    .await
+    {
+        Ok((tx, rows)) => {
+            transaction = tx.unwrap();
+            milestones = rows.into_iter().map(|x| x.milestone).collect();
+        }
+        Err(res) => return res,
+    }
+
+    if let Some(milestone) = milestones
+        .into_iter()
+        .filter(|&milestone| i64::from(milestone) > upvotes_before)
+        .max()
+    {
+        if let Err(err) = notify_participants(
+            &state,
+            &mut transaction,
+            id,
+            NotificationEvent::Upvoted,
+            None,
+            &format!("Quote #{id} reached {milestone} upvotes. Check it out at Quotefault!"),
+        )
+        .await
+        {
+            return SqlxErrorOrResponse::from(err).error_response();
+        }
+    }
+
+    match transaction.commit().await {
+        Ok(_) => HttpResponse::Ok().body(""),
+        Err(e) => {
+            log!(Level::Error, "Transaction failed to commit");
+            HttpResponse::InternalServerError().body(e.to_string())
+        }
+    }
+}
+
+/// Removes a vote from a quote
+#[utoipa::path(
+    delete,
+    path = "/api/quote/{id}/vote",
+    params(
+        ("id" = i32, Path, description = "ID of quote to unvote")
+    ),
+    responses(
+        (status = OK, description = "Removed vote from quote successfully"),
+        (status = NOT_FOUND, description = "Quote not found"),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[delete("/quote/{id}/vote", wrap = "CSHAuth::enabled()")]
+pub async fn unvote_quote(state: Data<AppState>, path: Path<(i32,)>, user: User) -> impl Responder {
+    let (id,) = path.into_inner();
+
+    let mut transaction = match open_transaction(&state.db).await {
+        Ok(t) => t,
+        Err(res) => return res,
+    };
+
+    match log_query(
+        query!(
+            "DELETE FROM votes 
+            WHERE quote_id=$1 AND submitter=$2
+            AND $1 IN (
+                SELECT id FROM quotes
+                WHERE CASE WHEN $3 THEN true ELSE id NOT IN (SELECT quote_id FROM hidden) END
+            )",
+            id,
+            user.preferred_username,
+            user.admin() || !*SECURITY_ENABLED
+        )
+        .execute(&mut *transaction)
+        .await,
+        Some(transaction),
+    )
+    .await
+    {
+        Ok((tx, result)) => {
+            transaction = tx.unwrap();
+            if result.rows_affected() == 0 {
+                return HttpResponse::NotFound().body("Quote does not exist");
+            }
+        }
+        Err(res) => return res,
+    }
+
+    match transaction.commit().await {
+        Ok(_) => HttpResponse::Ok().body(""),
+        Err(e) => {
+            log!(Level::Error, "Transaction failed to commit");
+            HttpResponse::InternalServerError().body(e.to_string())
+        }
+    }
+}
+
+/// Adds a reaction to a quote
+#[utoipa::path(
+    post,
+    path = "/api/quote/{id}/reaction",
+    params(
+        ("id" = i32, Path, description = "ID of quote to react to"),
+        ReactionParams
+    ),
+    responses(
+        (status = OK, description = "Reacted to quote successfully"),
+        (status = BAD_REQUEST, description = "Emoji is not an allowed reaction"),
+        (status = NOT_FOUND, description = "Quote not found"),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[post("/quote/{id}/reaction", wrap = "CSHAuth::enabled()")]
+pub async fn react_quote(
+    state: Data<AppState>,
+    path: Path<(i32,)>,
+    params: web::Query<ReactionParams>,
+    user: User,
+) -> impl Responder {
+    let (id,) = path.into_inner();
+
+    if !REACTION_ALLOWLIST.contains(&params.emoji) {
+        return HttpResponse::BadRequest().body("That emoji is not an allowed reaction.");
+    }
+
+    let mut transaction = match open_transaction(&state.db).await {
+        Ok(t) => t,
+        Err(res) => return res,
+    };
+
+    match log_query(
+        query!(
+            "INSERT INTO reactions (quote_id, username, emoji)
+            SELECT $1, $2, $3
+            WHERE $1 IN (
+                SELECT id FROM quotes
+                WHERE CASE WHEN $4 THEN true ELSE id NOT IN (SELECT quote_id FROM hidden) END
+            )
+            ON CONFLICT (quote_id, username, emoji)
+            DO UPDATE SET timestamp=reactions.timestamp",
+            id,
+            user.preferred_username,
+            params.emoji,
+            user.admin() || !*SECURITY_ENABLED
+        )
+        .execute(&mut *transaction)
+        .await,
+        Some(transaction),
+    )
+    .await
+    {
+        Ok((tx, result)) => {
+            transaction = tx.unwrap();
+            if result.rows_affected() == 0 {
+                return HttpResponse::NotFound().body("Quote does not exist");
+            }
+        }
+        Err(res) => return res,
+    }
+
+    match transaction.commit().await {
+        Ok(_) => HttpResponse::Ok().body(""),
+        Err(e) => {
+            log!(Level::Error, "Transaction failed to commit");
+            HttpResponse::InternalServerError().body(e.to_string())
+        }
+    }
+}
+
+/// Removes a reaction from a quote
+#[utoipa::path(
+    delete,
+    path = "/api/quote/{id}/reaction",
+    params(
+        ("id" = i32, Path, description = "ID of quote to remove the reaction from"),
+        ReactionParams
+    ),
+    responses(
+        (status = OK, description = "Removed reaction from quote successfully"),
+        (status = NOT_FOUND, description = "Quote or reaction not found"),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[delete("/quote/{id}/reaction", wrap = "CSHAuth::enabled()")]
+pub async fn unreact_quote(
+    state: Data<AppState>,
+    path: Path<(i32,)>,
+    params: web::Query<ReactionParams>,
+    user: User,
+) -> impl Responder {
+    let (id,) = path.into_inner();
+
+    let mut transaction = match open_transaction(&state.db).await {
+        Ok(t) => t,
+        Err(res) => return res,
+    };
+
+    match log_query(
+        query!(
+            "DELETE FROM reactions
+            WHERE quote_id=$1 AND username=$2 AND emoji=$3
+            AND $1 IN (
+                SELECT id FROM quotes
+                WHERE CASE WHEN $4 THEN true ELSE id NOT IN (SELECT quote_id FROM hidden) END
+            )",
+            id,
+            user.preferred_username,
+            params.emoji,
+            user.admin() || !*SECURITY_ENABLED
+        )
+        .execute(&mut *transaction)
+        .await,
+        Some(transaction),
+    )
+    .await
+    {
+        Ok((tx, result)) => {
+            transaction = tx.unwrap();
+            if result.rows_affected() == 0 {
+                return HttpResponse::NotFound().body("Quote or reaction does not exist");
+            }
+        }
+        Err(res) => return res,
+    }
+
+    match transaction.commit().await {
+        Ok(_) => HttpResponse::Ok().body(""),
+        Err(e) => {
+            log!(Level::Error, "Transaction failed to commit");
+            HttpResponse::InternalServerError().body(e.to_string())
+        }
+    }
+}
+
+/// Gets the emoji that quotes can be reacted to with
+#[utoipa::path(
+    get,
+    path = "/api/reactions",
+    responses(
+        (status = OK, description = "Successfully fetched reactions", body = Vec<String>),
+        (status = UNAUTHORIZED, description = "Not authenticated")
+    ),
+)]
+#[get("/reactions", wrap = "CSHAuth::enabled()")]
+pub async fn get_reactions() -> impl Responder {
+    HttpResponse::Ok().json(&*REACTION_ALLOWLIST)
+}
+
+/// Gets the list of users from LDAP
+#[utoipa::path(
+    get,
+    path = "/api/quotes",
+    params(FetchParams),
+    responses(
+        (status = OK, description = "Successfully searched quotes", body = PaginatedResponse<QuoteResponse, QuoteFilters>),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[get("/quotes", wrap = "CSHAuth::enabled()")]
+pub async fn get_quotes(
+    state: Data<AppState>,
+    params: web::Query<FetchParams>,
+    user: User,
+) -> impl Responder {
+    let limit = page_limit(params.limit);
+    let lt_qid: i32 = params.lt.unwrap_or(0);
+    let query = params.q.clone().unwrap_or_default();
+    let speaker = params.speaker.clone().unwrap_or("%".to_string());
+    let submitter = params.submitter.clone().unwrap_or("%".to_string());
+    let involved = params.involved.clone().unwrap_or("%".to_string());
+    let hidden = params.hidden.unwrap_or(false);
+    let filter_by_hidden = params.hidden.is_some();
+    let favorited = params.favorited.unwrap_or(false);
+    let tag = params.tag.as_deref().map(normalize_tag);
+    let sort = match params.sort.as_deref() {
+        Some("votes") => "votes",
+        Some("relevance") => "relevance",
+        _ => "timestamp",
+    };
+    let sort_direction = params.sort_direction.is_some_and(|d| d);
+    let cursor = match params.cursor.as_deref().map(QuoteCursor::decode) {
+        Some(Ok(cursor)) => {
+            if cursor.sort != sort || cursor.sort_direction != sort_direction {
+                return HttpResponse::BadRequest()
+                    .body("Cursor does not match the requested sort order.");
+            }
+            Some(cursor)
+        }
+        Some(Err(_)) => return HttpResponse::BadRequest().body("Invalid cursor."),
+        None => None,
+    };
+    let fetch = async |limit: i64, lt_qid: i32, cursor: Option<&QuoteCursor>| {
+        query_file_as!(
+            QuoteShard,
+            "queries/get_quotes.sql",
+            limit,                              // $1
+            lt_qid,                             // $2
+            query,                              // $3
+            speaker,                            // $4
+            submitter,                          // $5
+            hidden,                             // $6
+            filter_by_hidden,                   // $7
+            user.preferred_username,            // $8
+            user.admin() || !*SECURITY_ENABLED, // $9
+            involved,                           // $10
+            favorited,                          // $11
+            sort,                               // $12
+            sort_direction,                     // $13
+            cursor.map(|c| c.key),              // $14
+            cursor.map(|c| c.id),               // $15
+            tag,                                // $16
+        )
+        .fetch_all(&state.db)
+        .await
+    };
+    match log_query_as(
+        fetch(limit.saturating_add(1), lt_qid, cursor.as_ref()).await,
+        None,
+    )
+    .await
+    {
+        Ok((_, mut shards)) => {
+            let total = match shards.first() {
+                Some(shard) => shard.total.unwrap_or(0),
+                // Past the last page, so count the matches without paging
+                None if cursor.is_some() || lt_qid > 0 => {
+                    match log_query_as(fetch(1, 0, None).await, None).await {
+                        Ok((_, shards)) => shards.first().and_then(|x| x.total).unwrap_or(0),
+                        Err(res) => return res,
+                    }
+                }
+                None => 0,
+            };
+            let mut next_cursor = None;
+            if let Some(extra) = shards
+                .iter()
+                .filter(|x| x.index == 1)
+                .nth(limit as usize)
+                .map(|x| x.id)
+            {
+                shards.truncate(shards.iter().position(|x| x.id == extra).unwrap());
+                next_cursor = shards.last().and_then(|x| {
+                    Some(
+                        QuoteCursor {
+                            sort: sort.to_string(),
+                            sort_direction,
+                            key: x.sort_key?,
+                            id: x.id,
+                        }
+                        .encode(),
+                    )
+                });
+            }
+            match shards_to_quotes(shards.as_slice(), &state.ldap).await {
+                Ok(quotes) => HttpResponse::Ok().json(PaginatedResponse {
+                    items: quotes,
+                    total,
+                    next_cursor,
+                    filters: QuoteFilters {
+                        q: params.q.clone(),
+                        submitter: params.submitter.clone(),
+                        speaker: params.speaker.clone(),
+                        involved: params.involved.clone(),
+                        hidden: params.hidden,
+                        favorited,
+                        tag: params.tag.clone(),
+                        sort: sort.to_string(),
+                        sort_direction,
+                        limit,
+                    },
+                }),
+                Err(response) => response,
+            }
+        }
+        Err(res) => res,
+    }
+}
+
+/// Gets the list of hidden quotes
+#[utoipa::path(
+    get,
+    path = "/api/hidden",
+    params(HiddenParams),
+    responses(
+        (status = OK, description = "Successfully fetched hidden quotes", body = PaginatedResponse<QuoteResponse, HiddenFilters>),
+        (status = BAD_REQUEST, description = "Invalid cursor"),
+        (status = UNAUTHORIZED, description = "Not an admin"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[get("/hidden", wrap = "CSHAuth::admin_only()")]
+pub async fn get_hidden(
+    state: Data<AppState>,
+    params: web::Query<HiddenParams>,
+    user: User,
+) -> impl Responder {
+    let limit = page_limit(params.limit);
+    let lt_qid: i32 = params.lt.unwrap_or(0);
+    let actor = params.actor.clone().unwrap_or("%".to_string());
+    let cursor = match params.cursor.as_deref().map(QuoteCursor::decode) {
+        Some(Ok(cursor)) if cursor.sort == "hidden" => Some(cursor),
+        Some(_) => return HttpResponse::BadRequest().body("Invalid cursor."),
+        None => None,
+    };
+
+    let total = match log_query_as(
+        query!(
+            "SELECT COUNT(*) AS \"count!\" FROM hidden
+            WHERE actor LIKE $1
+            AND ($2::timestamp IS NULL OR timestamp IS NULL OR timestamp >= $2)
+            AND ($3::timestamp IS NULL OR timestamp IS NULL OR timestamp < $3)",
+            actor,
+            params.after,
+            params.before,
+        )
+        .fetch_all(&state.db)
+        .await,
+        None,
+    )
+    .await
+    {
+        Ok((_, result)) => result.first().map(|x| x.count).unwrap_or(0),
+        Err(res) => return res,
+    };
+
+    match log_query_as(
+        query_file_as!(
+            QuoteShard,
+            "queries/get_hidden_quotes.sql",
+            limit.saturating_add(1),       // $1
+            lt_qid,                        // $2
+            actor,                         // $3
+            params.after,                  // $4
+            params.before,                 // $5
+            user.preferred_username,       // $6
+            cursor.as_ref().map(|c| c.id), // $7
+        )
+        .fetch_all(&state.db)
+        .await,
+        None,
+    )
+    .await
+    {
+        Ok((_, mut shards)) => {
+            let mut next_cursor = None;
+            if let Some(extra) = shards
+                .iter()
+                .filter(|x| x.index == 1)
+                .nth(limit as usize)
+                .map(|x| x.id)
+            {
+                shards.truncate(shards.iter().position(|x| x.id == extra).unwrap());
+                next_cursor = shards.last().map(|x| {
+                    QuoteCursor {
+                        sort: "hidden".to_string(),
+                        sort_direction: false,
+                        key: x.id as f64,
+                        id: x.id,
+                    }
+                    .encode()
+                });
+            }
+            match shards_to_quotes(shards.as_slice(), &state.ldap).await {
+                Ok(quotes) => HttpResponse::Ok().json(PaginatedResponse {
+                    items: quotes,
+                    total,
+                    next_cursor,
+                    filters: HiddenFilters {
+                        actor: params.actor.clone(),
+                        after: params.after,
+                        before: params.before,
+                        limit,
+                    },
+                }),
+                Err(response) => response,
+            }
+        }
+        Err(res) => res,
+    }
+}
+
+/// Gets a user's profile and quote statistics
+#[utoipa::path(
+    get,
+    path = "/api/user/{uid}",
+    params(
+        ("uid" = String, Path, description = "Username of the user")
+    ),
+    responses(
+        (status = OK, description = "Successfully fetched user profile", body = UserProfileResponse),
+        (status = NOT_FOUND, description = "User not found"),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[get("/user/{uid}", wrap = "CSHAuth::enabled()")]
+pub async fn get_user_profile(
+    state: Data<AppState>,
+    path: Path<(String,)>,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    let (uid,) = path.into_inner();
+
+    if !is_valid_username(uid.as_str())
+        || !uid
+            .chars()
+            .all(|x| x.is_ascii_alphanumeric() || x == '-' || x == '_')
+    {
+        return Err(SqlxErrorOrResponse::Response(
+            StatusCode::NOT_FOUND,
+            "User could not be found",
+        ));
+    }
+    let kevlar_users: Vec<String> = get_kevlar_users(&state.db).await?.into_iter().collect();
+    if kevlar_users.contains(&uid) {
+        return Err(SqlxErrorOrResponse::Response(
+            StatusCode::NOT_FOUND,
+            "User could not be found",
+        ));
+    }
+    let ldap_user = match ldap::get_user(&state.ldap, uid.as_str()).await {
+        Ok(users) => match users.into_iter().next() {
+            Some(user) => user,
+            None => {
+                return Err(SqlxErrorOrResponse::Response(
+                    StatusCode::NOT_FOUND,
+                    "User could not be found",
+                ))
+            }
+        },
+        Err(err) => {
+            return Err(SqlxErrorOrResponse::ResponseOwned(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                err.to_string(),
+            ))
+        }
+    };
+
+    let stats = query_as!(
+        UserStats,
+        "WITH said AS (
+            SELECT DISTINCT s.quote_id FROM shards s
+            WHERE s.speaker = $1
+            AND s.quote_id NOT IN (SELECT quote_id FROM hidden)
+        )
+        SELECT
+            (SELECT COUNT(*) FROM said) AS \"quotes_said!\",
+            (
+                SELECT COUNT(*) FROM quotes q
+                WHERE q.submitter = $1
+                AND q.id NOT IN (SELECT quote_id FROM hidden)
+            ) AS \"quotes_submitted!\",
+            (
+                SELECT COALESCE(SUM(
+                    CASE
+                        WHEN v.vote='upvote' THEN 1
+                        WHEN v.vote='downvote' THEN -1
+                        ELSE 0
+                    END
+                ), 0) FROM votes v
+                WHERE v.quote_id IN (SELECT quote_id FROM said)
+            ) AS \"total_score!\",
+            (
+                SELECT COUNT(*) FROM favorites f
+                WHERE f.quote_id IN (SELECT quote_id FROM said)
+            ) AS \"favorites_received!\"",
+        uid,
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    let co_speakers = query_as!(
+        UserCount,
+        "SELECT s.speaker AS uid, COUNT(DISTINCT s.quote_id) AS \"count!\"
+        FROM shards s
+        WHERE s.quote_id IN (SELECT quote_id FROM shards WHERE speaker = $1)
+        AND s.quote_id NOT IN (SELECT quote_id FROM hidden)
+        AND s.speaker <> $1
+        AND s.speaker <> ALL($2)
+        GROUP BY s.speaker
+        ORDER BY 2 DESC, s.speaker
+        LIMIT 5",
+        uid,
+        kevlar_users.as_slice(),
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(HttpResponse::Ok().json(UserProfileResponse {
+        uid: ldap_user.uid,
+        cn: ldap_user.cn,
+        quotes_said: stats.quotes_said,
+        quotes_submitted: stats.quotes_submitted,
+        total_score: stats.total_score,
+        favorites_received: stats.favorites_received,
+        co_speakers: user_counts_to_responses(co_speakers.as_slice(), &state.ldap).await?,
+    }))
+}
+
+/// Gets house-wide leaderboards
+#[utoipa::path(
+    get,
+    path = "/api/stats",
+    params(StatsParams),
+    responses(
+        (status = OK, description = "Successfully fetched stats", body = StatsResponse),
+        (status = BAD_REQUEST, description = "Invalid time window"),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[get("/stats", wrap = "CSHAuth::enabled()")]
+pub async fn get_stats(
+    state: Data<AppState>,
+    params: web::Query<StatsParams>,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    let window = params.window.clone().unwrap_or("all".to_string());
+    let interval = match window.as_str() {
+        "day" => Some("1 day"),
+        "week" => Some("7 days"),
+        "month" => Some("30 days"),
+        "year" => Some("365 days"),
+        "all" => None,
+        _ => {
+            return Err(SqlxErrorOrResponse::Response(
+                StatusCode::BAD_REQUEST,
+                "Window must be one of day, week, month, year or all.",
+            ))
+        }
+    };
+    // Bounded in SQL, since quote timestamps are stored in the database's local time
+    let since = match interval {
+        Some(interval) => Some(
+            query!(
+                "SELECT LOCALTIMESTAMP - $1::text::interval AS \"since!\"",
+                interval
+            )
+            .fetch_one(&state.db)
+            .await?
+            .since,
+        ),
+        None => None,
+    };
+    let limit = params.limit.unwrap_or(10).clamp(1, 50);
+    let kevlar_users: Vec<String> = get_kevlar_users(&state.db).await?.into_iter().collect();
+
+    let counts = query_file_as!(
+        BoardCount,
+        "queries/get_stats.sql",
+        since,
+        kevlar_users.as_slice(),
+        limit,
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let user_board = |board: &str| -> Vec<UserCount> {
+        counts
+            .iter()
+            .filter(|x| x.board == board)
+            .filter_map(|x| {
+                Some(UserCount {
+                    uid: x.uid.clone()?,
+                    count: x.count,
+                })
+            })
+            .collect()
+    };
+    let quote_board = |board: &str| -> Vec<QuoteStatResponse> {
+        counts
+            .iter()
+            .filter(|x| x.board == board)
+            .filter_map(|x| {
+                Some(QuoteStatResponse {
+                    id: x.id?,
+                    count: x.count,
+                })
+            })
+            .collect()
+    };
+
+    Ok(HttpResponse::Ok().json(StatsResponse {
+        window,
+        since,
+        most_quoted: user_counts_to_responses(&user_board("most_quoted"), &state.ldap).await?,
+        top_submitters: user_counts_to_responses(&user_board("top_submitters"), &state.ldap)
+            .await?,
+        top_quotes: quote_board("top_quotes"),
+        most_favorited: quote_board("most_favorited"),
+    }))
+}
+
+/// Gets the reports filed by the user
+#[utoipa::path(
+    get,
+    path = "/api/me/reports",
+    responses(
+        (status = OK, description = "Successfully fetched reports", body = Vec<OwnReportResponse>),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[get("/me/reports", wrap = "CSHAuth::enabled()")]
+pub async fn get_own_reports(
+    state: Data<AppState>,
+    user: User,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    let hash = reporter_hash(&user.preferred_username);
+    let reports = query_as!(
+        OwnReport,
+        "SELECT id, quote_id, comment_id, category AS \"category: ReportCategory\", reason,
+            timestamp, resolver IS NOT NULL AS \"is_resolved!\", resolved,
+            outcome AS \"outcome: ReportOutcome\"
+        FROM reports
+        WHERE submitter_hash = $1
+        ORDER BY id DESC",
+        hash.as_slice(),
+    )
+    .fetch_all(&state.db)
+    .await?;
+    Ok(HttpResponse::Ok().json(
+        reports
+            .into_iter()
+            .map(|x| OwnReportResponse {
+                id: x.id,
+                quote_id: x.quote_id,
+                comment_id: x.comment_id,
+                category: x.category,
+                reason: x.reason,
+                timestamp: x.timestamp,
+                status: if x.is_resolved {
+                    ReportStatus::Resolved
+                } else {
+                    ReportStatus::Open
+                },
+                resolved: x.resolved,
+                outcome: x.outcome,
+            })
+            .collect::<Vec<_>>(),
+    ))
+}
+
+/// Withdraws an unresolved report filed by the user
+#[utoipa::path(
+    delete,
+    path = "/api/me/reports/{id}",
+    params(
+        ("id" = i32, Path, description = "ID of report to withdraw")
+    ),
+    responses(
+        (status = OK, description = "Report withdrawn"),
+        (status = BAD_REQUEST, description = "Report was not filed by the requester, is already resolved or does not exist"),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[delete("/me/reports/{id}", wrap = "CSHAuth::enabled()")]
+pub async fn withdraw_report(
+    state: Data<AppState>,
+    path: Path<(i32,)>,
+    user: User,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    let (id,) = path.into_inner();
+    let hash = reporter_hash(&user.preferred_username);
+
+    state
+        .db
+        .acquire()
+        .await?
+        .transaction(|transaction| {
+            Box::pin(async move {
+                // Withdrawn reports are deleted outright rather than recorded
+                // anywhere, so admins can't tell who withdrew them
+                let Some(report) = query!(
+                    "DELETE FROM reports
+                    WHERE id = $1 AND submitter_hash = $2 AND resolver IS NULL
+                    RETURNING quote_id",
+                    id,
+                    hash.as_slice(),
+                )
+                .fetch_optional(&mut **transaction)
+                .await?
+                else {
+                    return Err(SqlxErrorOrResponse::Response(
+                        StatusCode::BAD_REQUEST,
+                        "Either this is not your report, it has already been resolved or it does not exist.",
+                    ));
+                };
+                log!(Level::Trace, "withdrew report");
+
+                // Let the next report on this quote ping moderators again if
+                // nothing is left for them to review
+                query!(
+                    "DELETE FROM report_alerts
+                    WHERE quote_id = $1
+                    AND NOT EXISTS (
+                        SELECT 1 FROM reports WHERE quote_id = $1 AND resolver IS NULL
+                    )",
+                    report.quote_id,
+                )
+                .execute(&mut **transaction)
+                .await?;
+                Ok(())
+            })
+        })
+        .await?;
+    Ok(HttpResponse::Ok().body(""))
+}
+
+/// Gets the user's notification preferences
+#[utoipa::path(
+    get,
+    path = "/api/me/preferences",
+    responses(
+        (status = OK, description = "Successfully fetched preferences", body = Vec<PreferenceResponse>),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[get("/me/preferences", wrap = "CSHAuth::enabled()")]
+pub async fn get_preferences(
+    state: Data<AppState>,
+    user: User,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    let saved = query_as!(
+        Preference,
+        "SELECT event AS \"event: NotificationEvent\", channel, enabled
+        FROM notification_preferences
+        WHERE username = $1",
+        user.preferred_username,
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    // Anything the user hasn't set is on
+    let preferences: Vec<PreferenceResponse> = NotificationEvent::ALL
+        .iter()
+        .flat_map(|event| {
+            state.notifiers.iter().map(|notifier| PreferenceResponse {
+                event: *event,
+                channel: notifier.channel().to_string(),
+                enabled: saved
+                    .iter()
+                    .find(|x| x.event == *event && x.channel == notifier.channel())
+                    .is_none_or(|x| x.enabled),
+            })
+        })
+        .collect();
+    Ok(HttpResponse::Ok().json(preferences))
+}
+
+/// Updates the user's notification preferences
+#[utoipa::path(
+    put,
+    path = "/api/me/preferences",
+    request_body = Vec<PreferenceResponse>,
+    responses(
+        (status = OK, description = "Preferences updated successfully"),
+        (status = BAD_REQUEST, description = "Unknown channel"),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[put("/me/preferences", wrap = "CSHAuth::enabled()")]
+pub async fn update_preferences(
+    state: Data<AppState>,
+    body: Json<Vec<PreferenceResponse>>,
+    user: User,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    if body
+        .iter()
+        .any(|x| !state.notifiers.iter().any(|n| n.channel() == x.channel))
+    {
+        return Err(SqlxErrorOrResponse::Response(
+            StatusCode::BAD_REQUEST,
+            "Unknown notification channel.",
+        ));
+    }
+
+    let events: Vec<NotificationEvent> = body.iter().map(|x| x.event).collect();
+    let channels: Vec<String> = body.iter().map(|x| x.channel.clone()).collect();
+    let enabled: Vec<bool> = body.iter().map(|x| x.enabled).collect();
+    query!(
+        "INSERT INTO notification_preferences (username, event, channel, enabled)
+        SELECT DISTINCT ON (event, channel) $1, event, channel, enabled
+        FROM UNNEST($2::notification_event[], $3::varchar[], $4::bool[])
+            WITH ORDINALITY AS a(event, channel, enabled, ord)
+        ORDER BY event, channel, ord DESC
+        ON CONFLICT (username, event, channel) DO UPDATE SET enabled = EXCLUDED.enabled",
+        user.preferred_username,
+        events.as_slice() as &[NotificationEvent],
+        channels.as_slice(),
+        enabled.as_slice(),
+    )
+    .execute(&state.db)
+    .await?;
+    Ok(HttpResponse::Ok().body(""))
+}
+
+/// Gets queued and failed notifications
+#[utoipa::path(
+    get,
+    path = "/api/notifications",
+    params(OutboxParams),
+    responses(
+        (status = OK, description = "Successfully fetched notifications", body = Vec<OutboxResponse>),
+        (status = UNAUTHORIZED, description = "Not authenticated or not an admin"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[get("/notifications", wrap = "CSHAuth::admin_only()")]
+pub async fn get_notifications(
+    state: Data<AppState>,
+    params: web::Query<OutboxParams>,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    let limit = page_limit(params.limit);
+    let notifications = query_as!(
+        OutboxResponse,
+        "SELECT id, username, channel, body, status AS \"status: NotificationStatus\",
+            attempts, next_attempt, last_error, timestamp, delivered
+        FROM notification_outbox
+        WHERE ($1::notification_status IS NULL OR status = $1)
+        AND CASE WHEN $2::int4 > 0 THEN id < $2::int4 ELSE true END
+        ORDER BY id DESC
+        LIMIT $3",
+        params.status.clone() as Option<NotificationStatus>,
+        params.lt.unwrap_or(0),
+        limit,
+    )
+    .fetch_all(&state.db)
+    .await?;
+    Ok(HttpResponse::Ok().json(notifications))
+}
 
-    match transaction.commit().await {
-        Ok(_) => HttpResponse::Ok().body(""),
-        Err(e) => {
-            log!(Level::Error, "Transaction failed to commit");
-            HttpResponse::InternalServerError().body(e.to_string())
-        }
+/// Requeues a dead notification for delivery
+#[utoipa::path(
+    post,
+    path = "/api/notifications/{id}/replay",
+    params(
+        ("id" = i32, Path, description = "ID of notification to replay")
+    ),
+    responses(
+        (status = OK, description = "Notification requeued"),
+        (status = BAD_REQUEST, description = "Notification does not exist or is not dead"),
+        (status = UNAUTHORIZED, description = "Not authenticated or not an admin"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[post("/notifications/{id}/replay", wrap = "CSHAuth::admin_only()")]
+pub async fn replay_notification(
+    state: Data<AppState>,
+    path: Path<(i32,)>,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    let (id,) = path.into_inner();
+    let result = query!(
+        "UPDATE notification_outbox
+        SET status = 'pending', attempts = 0, next_attempt = NOW()
+        WHERE id = $1 AND status = 'dead'",
+        id,
+    )
+    .execute(&state.db)
+    .await?;
+    if result.rows_affected() == 0 {
+        return Err(SqlxErrorOrResponse::Response(
+            StatusCode::BAD_REQUEST,
+            "Either this notification does not exist or it is not dead.",
+        ));
     }
+    Ok(HttpResponse::Ok().body(""))
 }
 
-/// Gets the list of users from LDAP
+/// Gets entries from the moderation audit log
 #[utoipa::path(
     get,
-    path = "/api/quotes",
-    params(FetchParams),
+    path = "/api/audit",
+    params(AuditParams),
     responses(
-        (status = OK, description = "Successfully searched quotes", body = Vec<QuoteResponse>),
-        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = OK, description = "Successfully fetched audit log", body = Vec<AuditResponse>),
+        (status = UNAUTHORIZED, description = "Not authenticated or not an admin"),
         (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
     ),
 )]
-#[get("/quotes", wrap = "CSHAuth::enabled()")]
-pub async fn get_quotes(
+#[get("/audit", wrap = "CSHAuth::admin_only()")]
+pub async fn get_audit_log(
     state: Data<AppState>,
-    params: web::Query<FetchParams>,
-    user: User,
-) -> impl Responder {
-    let limit: i64 = params
-        .limit
-        .map(|x| if x == -1 { i64::MAX } else { x })
-        .unwrap_or(10);
-    let lt_qid: i32 = params.lt.unwrap_or(0);
-    let query = params
-        .q
-        .clone()
-        .map_or("%".to_string(), |q| format!("%{q}%"));
-    let speaker = params.speaker.clone().unwrap_or("%".to_string());
-    let submitter = params.submitter.clone().unwrap_or("%".to_string());
-    let involved = params.involved.clone().unwrap_or("%".to_string());
-    let hidden = params.hidden.unwrap_or(false);
-    let filter_by_hidden = params.hidden.is_some();
-    let favorited = params.favorited.unwrap_or(false);
-    let sort = params.sort.as_ref().is_some_and(|s| s == "votes");
-    let sort_direction = params.sort_direction.is_some_and(|d| d);
-    match log_query_as(
-        query_file_as!(
-            QuoteShard,
-            "queries/get_quotes.sql",
-            limit,                              // $1
-            lt_qid,                             // $2
-            query,                              // $3
-            speaker,                            // $4
-            submitter,                          // $5
-            hidden,                             // $6
-            filter_by_hidden,                   // $7
-            user.preferred_username,            // $8
-            user.admin() || !*SECURITY_ENABLED, // $9
-            involved,                           // $10
-            favorited,                          // $11
-            sort,                               // $12
-            sort_direction,                     // $13
-        )
-        .fetch_all(&state.db)
-        .await,
-        None,
+    params: web::Query<AuditParams>,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    let limit = page_limit(params.limit);
+    let entries = query_as!(
+        AuditResponse,
+        "SELECT id, actor, action AS \"action: AuditAction\", quote_id, details, timestamp
+        FROM audit_log
+        WHERE ($1::varchar IS NULL OR actor = $1)
+        AND ($2::int4 IS NULL OR quote_id = $2)
+        AND ($3::timestamp IS NULL OR timestamp >= $3)
+        AND ($4::timestamp IS NULL OR timestamp < $4)
+        AND CASE WHEN $5::int4 > 0 THEN id < $5::int4 ELSE true END
+        ORDER BY id DESC
+        LIMIT $6",
+        params.actor,
+        params.quote_id,
+        params.after,
+        params.before,
+        params.lt.unwrap_or(0),
+        limit,
     )
-    .await
-    {
-        Ok((_, shards)) => match shards_to_quotes(shards.as_slice(), &state.ldap).await {
-            Ok(quotes) => HttpResponse::Ok().json(quotes),
-            Err(response) => response,
-        },
-        Err(res) => res,
-    }
+    .fetch_all(&state.db)
+    .await?;
+    Ok(HttpResponse::Ok().json(entries))
 }
 
 /// Gets the list of users
@@ -829,21 +3269,50 @@ pub async fn get_users(state: Data<AppState>) -> impl Responder {
 #[utoipa::path(
     get,
     path = "/api/reports",
+    params(ReportParams),
     responses(
-        (status = OK, description = "Successfully fetched reports", body = Vec<ReportResponse>),
+        (status = OK, description = "Successfully fetched reports", body = PaginatedResponse<ReportedQuoteResponse, ReportFilters>),
+        (status = BAD_REQUEST, description = "Invalid cursor"),
         (status = UNAUTHORIZED, description = "Not an admin"),
         (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
     ),
 )]
 #[get("/reports", wrap = "CSHAuth::admin_only()")]
-pub async fn get_reports(state: Data<AppState>) -> impl Responder {
+pub async fn get_reports(
+    state: Data<AppState>,
+    params: web::Query<ReportParams>,
+) -> impl Responder {
+    let limit = page_limit(params.limit);
+    let cursor = match params.cursor.as_deref().map(QuoteCursor::decode) {
+        Some(Ok(cursor)) if cursor.sort == "reports" => Some(cursor),
+        Some(_) => return HttpResponse::BadRequest().body("Invalid cursor."),
+        None => None,
+    };
+
+    let total = match log_query_as(
+        query!(
+            "SELECT COUNT(DISTINCT quote_id) AS \"count!\" FROM reports
+            WHERE resolver IS NULL AND ($1::report_category IS NULL OR category = $1)",
+            params.category as Option<ReportCategory>,
+        )
+        .fetch_all(&state.db)
+        .await,
+        None,
+    )
+    .await
+    {
+        Ok((_, result)) => result.first().map(|x| x.count).unwrap_or(0),
+        Err(res) => return res,
+    };
+
     match log_query_as(
         query_as!(
             ReportedQuoteShard,
             "SELECT pq.id AS \"quote_id!\", pq.submitter AS \"quote_submitter!\",
-            pq.timestamp AS \"quote_timestamp!\", pq.hidden AS \"quote_hidden!\", 
+            pq.timestamp AS \"quote_timestamp!\", pq.hidden AS \"quote_hidden!\",
             r.timestamp AS \"report_timestamp!\", r.id AS \"report_id!\",
-            r.reason AS \"report_reason!\", r.resolver AS \"report_resolver\"
+            r.category AS \"report_category: ReportCategory\", r.reason AS \"report_reason\",
+            r.resolver AS \"report_resolver\", r.comment_id AS \"report_comment_id\"
             FROM (
                 SELECT * FROM (
                     SELECT id, submitter, timestamp,
@@ -854,10 +3323,18 @@ pub async fn get_reports(state: Data<AppState>) -> impl Responder {
                 WHERE q.id IN (
                     SELECT quote_id FROM reports r
                     WHERE r.resolver IS NULL
+                    AND ($1::report_category IS NULL OR r.category = $1)
                 )
+                AND ($2::int4 IS NULL OR q.id > $2)
+                ORDER BY q.id
+                LIMIT $3
             ) AS pq
-            LEFT JOIN reports r ON r.quote_id = pq.id WHERE r.resolver IS NULL
-            ORDER BY pq.id, r.id"
+            LEFT JOIN reports r ON r.quote_id = pq.id
+            WHERE r.resolver IS NULL AND ($1::report_category IS NULL OR r.category = $1)
+            ORDER BY pq.id, r.id",
+            params.category as Option<ReportCategory>,
+            cursor.as_ref().map(|c| c.id),
+            limit.saturating_add(1),
         )
         .fetch_all(&state.db)
         .await,
@@ -865,11 +3342,161 @@ pub async fn get_reports(state: Data<AppState>) -> impl Responder {
     )
     .await
     {
-        Ok((_, reports)) => HttpResponse::Ok().json(format_reports(reports.as_slice())),
+        Ok((_, reports)) => {
+            let mut reports = format_reports(reports.as_slice());
+            let mut next_cursor = None;
+            if reports.len() as i64 > limit {
+                reports.truncate(limit as usize);
+                next_cursor = reports.last().map(|x| {
+                    QuoteCursor {
+                        sort: "reports".to_string(),
+                        sort_direction: true,
+                        key: x.quote_id as f64,
+                        id: x.quote_id,
+                    }
+                    .encode()
+                });
+            }
+            HttpResponse::Ok().json(PaginatedResponse {
+                items: reports,
+                total,
+                next_cursor,
+                filters: ReportFilters {
+                    category: params.category,
+                    limit,
+                },
+            })
+        }
         Err(res) => res,
     }
 }
 
+/// Gets the history of resolved reports
+#[utoipa::path(
+    get,
+    path = "/api/reports/resolved",
+    params(ResolvedReportParams),
+    responses(
+        (status = OK, description = "Successfully fetched resolved reports", body = PaginatedResponse<ResolvedReportResponse, ResolvedReportFilters>),
+        (status = BAD_REQUEST, description = "Invalid cursor"),
+        (status = UNAUTHORIZED, description = "Not an admin"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[get("/reports/resolved", wrap = "CSHAuth::admin_only()")]
+pub async fn get_resolved_reports(
+    state: Data<AppState>,
+    params: web::Query<ResolvedReportParams>,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    let limit = page_limit(params.limit);
+    let cursor = match params.cursor.as_deref().map(QuoteCursor::decode) {
+        Some(Ok(cursor)) if cursor.sort == "resolved" => Some(cursor.id),
+        Some(_) => {
+            return Err(SqlxErrorOrResponse::Response(
+                StatusCode::BAD_REQUEST,
+                "Invalid cursor.",
+            ))
+        }
+        None => None,
+    };
+
+    let total = query!(
+        "SELECT COUNT(*) AS \"count!\" FROM reports r
+        WHERE r.resolver IS NOT NULL
+        AND ($1::report_category IS NULL OR r.category = $1)
+        AND ($2::report_outcome IS NULL OR r.outcome = $2)
+        AND ($3::varchar IS NULL OR r.resolver = $3)
+        AND ($4::int4 IS NULL OR r.quote_id = $4)
+        AND ($5::timestamp IS NULL OR r.resolved >= $5)
+        AND ($6::timestamp IS NULL OR r.resolved < $6)",
+        params.category as Option<ReportCategory>,
+        params.outcome as Option<ReportOutcome>,
+        params.resolver,
+        params.quote_id,
+        params.after,
+        params.before,
+    )
+    .fetch_one(&state.db)
+    .await?
+    .count;
+
+    // One extra report is fetched to tell whether there's another page
+    let mut reports = query_as!(
+        ResolvedReport,
+        "SELECT r.id, r.quote_id, r.comment_id,
+            r.category AS \"category: ReportCategory\", r.reason, r.timestamp,
+            r.resolver AS \"resolver!\", r.resolved,
+            r.outcome AS \"outcome: ReportOutcome\", r.notes,
+            EXISTS (SELECT 1 FROM hidden h WHERE h.quote_id = r.quote_id)
+                AS \"quote_currently_hidden!\"
+        FROM reports r
+        WHERE r.resolver IS NOT NULL
+        AND ($1::report_category IS NULL OR r.category = $1)
+        AND ($2::report_outcome IS NULL OR r.outcome = $2)
+        AND ($3::varchar IS NULL OR r.resolver = $3)
+        AND ($4::int4 IS NULL OR r.quote_id = $4)
+        AND ($5::timestamp IS NULL OR r.resolved >= $5)
+        AND ($6::timestamp IS NULL OR r.resolved < $6)
+        AND ($7::int4 IS NULL OR r.id < $7)
+        ORDER BY r.id DESC
+        LIMIT $8",
+        params.category as Option<ReportCategory>,
+        params.outcome as Option<ReportOutcome>,
+        params.resolver,
+        params.quote_id,
+        params.after,
+        params.before,
+        cursor,
+        limit.saturating_add(1),
+    )
+    .fetch_all(&state.db)
+    .await?;
+    let next_cursor = if reports.len() as i64 > limit {
+        reports.truncate(limit as usize);
+        reports.last().map(|x| {
+            QuoteCursor {
+                sort: "resolved".to_string(),
+                sort_direction: false,
+                key: x.id as f64,
+                id: x.id,
+            }
+            .encode()
+        })
+    } else {
+        None
+    };
+
+    Ok(HttpResponse::Ok().json(PaginatedResponse {
+        items: reports
+            .into_iter()
+            .map(|x| ResolvedReportResponse {
+                id: x.id,
+                quote_id: x.quote_id,
+                comment_id: x.comment_id,
+                category: x.category,
+                reason: x.reason,
+                timestamp: x.timestamp,
+                resolver: x.resolver,
+                resolved: x.resolved,
+                outcome: x.outcome,
+                notes: x.notes,
+                quote_currently_hidden: x.quote_currently_hidden,
+            })
+            .collect::<Vec<_>>(),
+        total,
+        next_cursor,
+        filters: ResolvedReportFilters {
+            category: params.category,
+            outcome: params.outcome,
+            resolver: params.resolver.clone(),
+            quote_id: params.quote_id,
+            after: params.after,
+            before: params.before,
+            limit,
+        },
+    }))
+}
+
 impl From<sqlx::Error> for SqlxErrorOrResponse<'_> {
     fn from(error: sqlx::Error) -> Self {
         Self::SqlxError(error)
@@ -881,12 +3508,13 @@ impl From<sqlx::Error> for SqlxErrorOrResponse<'_> {
     put,
     path = "/api/quote/{id}/resolve",
     params(
-        ("id" = i32, Path, description = "ID of report to resolve"),
+        ("id" = i32, Path, description = "ID of quote whose reports to resolve"),
         ResolveParams
     ),
+    request_body = Option<Resolution>,
     responses(
         (status = OK, description = "Successfully resolved a report"),
-        (status = BAD_REQUEST, description = "Report is either already resolved or doesn't exist"),
+        (status = BAD_REQUEST, description = "No open reports and nothing to overturn, or the outcome doesn't match whether the quote is hidden"),
         (status = UNAUTHORIZED, description = "Not an admin"),
         (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
     ),
@@ -897,35 +3525,202 @@ pub async fn resolve_report(
     path: Path<(i32,)>,
     user: User,
     params: web::Query<ResolveParams>,
+    body: Bytes,
 ) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
     let (id,) = path.into_inner();
 
-    state.db.acquire().await?.transaction(|transaction| Box::pin(async move {
-
-        let result = match query!(
-            "UPDATE reports SET resolver=$1 WHERE quote_id=$2 AND resolver IS NULL RETURNING reason",
-            user.preferred_username,
-            id,
-        )
-            .fetch_one(&mut **transaction)
-            .await {
-                Ok(result) => result,
-                Err(sqlx::Error::RowNotFound) =>
-                {
-                    return Err(SqlxErrorOrResponse::Response(StatusCode::BAD_REQUEST, "Report is either already resolved or doesn't exist."));
-                },
-                Err(err) => return Err(err.into()),
-            };
+    // The body is optional, but a malformed one mustn't fall back to dismissing
+    let body: Resolution = if body.is_empty() {
+        Resolution::default()
+    } else {
+        serde_json::from_slice(&body).map_err(|err| {
+            SqlxErrorOrResponse::ResponseOwned(StatusCode::BAD_REQUEST, err.to_string())
+        })?
+    };
 
-        log!(Level::Trace, "resolved all quote's reports");
+    if let (Some(true), Some(true)) = (params.hide, params.unhide) {
+        return Err(SqlxErrorOrResponse::Response(
+            StatusCode::BAD_REQUEST,
+            "Cannot both hide and unhide a quote.",
+        ));
+    }
 
-        if let Some(true) = params.hide {
-            hide_quote_by_id(id, user, result.reason, &mut *transaction).await?;
+    let outcome = match body.outcome {
+        Some(outcome) => outcome,
+        None if params.hide == Some(true) => ReportOutcome::Hidden,
+        None if params.unhide == Some(true) => ReportOutcome::Unhidden,
+        None => ReportOutcome::Dismissed,
+    };
+    if (params.hide == Some(true) && outcome != ReportOutcome::Hidden)
+        || (params.unhide == Some(true) && outcome != ReportOutcome::Unhidden)
+    {
+        return Err(SqlxErrorOrResponse::Response(
+            StatusCode::BAD_REQUEST,
+            "Outcome does not match hide or unhide.",
+        ));
+    }
+    let comment_id = params.comment_id;
+    match (comment_id, outcome) {
+        (Some(_), ReportOutcome::Hidden | ReportOutcome::Unhidden) => {
+            return Err(SqlxErrorOrResponse::Response(
+                StatusCode::BAD_REQUEST,
+                "Comment reports cannot hide or unhide the quote.",
+            ));
         }
+        (None, ReportOutcome::Removed) => {
+            return Err(SqlxErrorOrResponse::Response(
+                StatusCode::BAD_REQUEST,
+                "Only comments can be removed while resolving reports.",
+            ));
+        }
+        _ => {}
+    }
+    let notes = body
+        .notes
+        .as_deref()
+        .map(str::trim)
+        .filter(|notes| !notes.is_empty())
+        .map(str::to_string);
 
-        Ok(())
+    state
+        .db
+        .acquire()
+        .await?
+        .transaction(|transaction| {
+            Box::pin(async move {
+                let hidden = query!(
+                    "SELECT EXISTS (SELECT 1 FROM hidden WHERE quote_id = $1) AS \"hidden!\"",
+                    id,
+                )
+                .fetch_one(&mut **transaction)
+                .await?
+                .hidden;
+                match outcome {
+                    ReportOutcome::Hidden if hidden => {
+                        return Err(SqlxErrorOrResponse::Response(
+                            StatusCode::BAD_REQUEST,
+                            "Quote is already hidden.",
+                        ));
+                    }
+                    ReportOutcome::Unhidden if !hidden => {
+                        return Err(SqlxErrorOrResponse::Response(
+                            StatusCode::BAD_REQUEST,
+                            "Quote is not hidden.",
+                        ));
+                    }
+                    _ => {}
+                }
+
+                let mut resolved = query!(
+                    "UPDATE reports SET resolver=$1, outcome=$3, notes=$4, resolved=NOW()
+                    WHERE quote_id=$2 AND resolver IS NULL
+                    AND comment_id IS NOT DISTINCT FROM $5
+                    RETURNING reason, category AS \"category: ReportCategory\"",
+                    user.preferred_username,
+                    id,
+                    outcome as ReportOutcome,
+                    notes,
+                    comment_id,
+                )
+                .fetch_all(&mut **transaction)
+                .await?
+                .into_iter()
+                .map(|x| (x.reason, x.category))
+                .collect::<Vec<_>>();
+
+                // With nothing open, the only thing left to do is overturn a
+                // resolution that hid the quote by unhiding it. The original
+                // resolution is kept as it was and the overturn is audited
+                // on its own
+                let overturned = resolved.is_empty();
+                if overturned {
+                    let latest = query!(
+                        "SELECT outcome AS \"outcome: ReportOutcome\" FROM reports
+                        WHERE quote_id = $1 AND comment_id IS NULL AND resolver IS NOT NULL
+                        ORDER BY resolved DESC NULLS LAST, id DESC
+                        LIMIT 1",
+                        id,
+                    )
+                    .fetch_optional(&mut **transaction)
+                    .await?
+                    .and_then(|x| x.outcome);
+                    if outcome != ReportOutcome::Unhidden
+                        || latest != Some(ReportOutcome::Hidden)
+                    {
+                        return Err(SqlxErrorOrResponse::Response(
+                            StatusCode::BAD_REQUEST,
+                            "There are no open reports. Only a quote hidden while resolving reports can be unhidden.",
+                        ));
+                    }
+                    log!(Level::Trace, "overturned quote's latest resolution");
+                } else {
+                    log!(Level::Trace, "resolved all quote's reports");
+                }
+
+                let details = match (comment_id, &notes) {
+                    (Some(comment_id), Some(notes)) => {
+                        Some(format!("Comment #{comment_id}: {notes}"))
+                    }
+                    (Some(comment_id), None) => Some(format!("Comment #{comment_id}")),
+                    (None, Some(notes)) if overturned => {
+                        Some(format!("Overturned hiding: {notes}"))
+                    }
+                    (None, None) if overturned => Some("Overturned hiding".to_string()),
+                    (None, notes) => notes.clone(),
+                };
+                record_audit(
+                    transaction,
+                    &user.preferred_username,
+                    AuditAction::Resolve,
+                    Some(id),
+                    details.as_deref(),
+                )
+                .await?;
+
+                // Moderators are pinged again for the next report once nothing
+                // on the quote is left for them to review
+                query!(
+                    "DELETE FROM report_alerts
+                    WHERE quote_id = $1
+                    AND NOT EXISTS (
+                        SELECT 1 FROM reports WHERE quote_id = $1 AND resolver IS NULL
+                    )",
+                    id,
+                )
+                .execute(&mut **transaction)
+                .await?;
+
+                if let (Some(comment_id), ReportOutcome::Removed) = (comment_id, outcome) {
+                    query!(
+                        "DELETE FROM comments WHERE id = $1 AND quote_id = $2",
+                        comment_id,
+                        id,
+                    )
+                    .execute(&mut **transaction)
+                    .await?;
+                    log!(Level::Trace, "removed reported comment");
+                }
 
-    })).await?;
+                if outcome == ReportOutcome::Hidden {
+                    let (reason, category) = resolved.swap_remove(0);
+                    let reason = reason.unwrap_or_else(|| category.description().to_string());
+                    hide_quote_by_id(id, user, reason, &state, &mut *transaction).await?;
+                } else if outcome == ReportOutcome::Unhidden {
+                    unhide_quote_by_id(
+                        id,
+                        user,
+                        notes
+                            .clone()
+                            .unwrap_or_else(|| "Restored while resolving reports".to_string()),
+                        &mut *transaction,
+                    )
+                    .await?;
+                }
+
+                Ok(())
+            })
+        })
+        .await?;
 
     Ok(HttpResponse::Ok().body(""))
 }
@@ -1046,6 +3841,204 @@ pub async fn unfavorite_quote(
     }
 }
 
+/// Gets the list of tags
+#[utoipa::path(
+    get,
+    path = "/api/tags",
+    responses(
+        (status = OK, description = "Successfully fetched tags", body = Vec<TagResponse>),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[get("/tags", wrap = "CSHAuth::enabled()")]
+pub async fn get_tags(state: Data<AppState>) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    let tags = query_as!(
+        TagResponse,
+        "SELECT t.name, COUNT(qt.quote_id) AS \"count!\"
+        FROM tags t
+        LEFT JOIN (
+            SELECT * FROM quote_tags
+            WHERE quote_id NOT IN (SELECT quote_id FROM hidden)
+        ) qt ON qt.tag_id = t.id
+        GROUP BY t.id
+        ORDER BY t.name"
+    )
+    .fetch_all(&state.db)
+    .await?;
+    Ok(HttpResponse::Ok().json(tags))
+}
+
+/// Suggests tags starting with a prefix, most used first
+#[utoipa::path(
+    get,
+    path = "/api/tags/autocomplete",
+    params(TagParams),
+    responses(
+        (status = OK, description = "Successfully fetched tag suggestions", body = Vec<TagResponse>),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[get("/tags/autocomplete", wrap = "CSHAuth::enabled()")]
+pub async fn autocomplete_tags(
+    state: Data<AppState>,
+    params: web::Query<TagParams>,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    let prefix = params
+        .q
+        .as_ref()
+        .map(|x| {
+            x.trim()
+                .to_lowercase()
+                .replace('%', "\\%")
+                .replace('_', "\\_")
+        })
+        .unwrap_or_default();
+    let tags = query_as!(
+        TagResponse,
+        "SELECT t.name, COUNT(qt.quote_id) AS \"count!\"
+        FROM tags t
+        LEFT JOIN (
+            SELECT * FROM quote_tags
+            WHERE quote_id NOT IN (SELECT quote_id FROM hidden)
+        ) qt ON qt.tag_id = t.id
+        WHERE t.name LIKE $1 || '%'
+        GROUP BY t.id
+        ORDER BY COUNT(qt.quote_id) DESC, t.name
+        LIMIT $2",
+        prefix,
+        params.limit.unwrap_or(10).clamp(0, 100),
+    )
+    .fetch_all(&state.db)
+    .await?;
+    Ok(HttpResponse::Ok().json(tags))
+}
+
+/// Creates a tag
+#[utoipa::path(
+    post,
+    path = "/api/tags",
+    request_body = NewTag,
+    responses(
+        (status = OK, description = "Tag created successfully"),
+        (status = BAD_REQUEST, description = "Tag is malformed or already exists"),
+        (status = UNAUTHORIZED, description = "Not authenticated"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[post("/tags", wrap = "CSHAuth::enabled()")]
+pub async fn create_tag(
+    state: Data<AppState>,
+    Json(tag): Json<NewTag>,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    let name = normalize_tag(&tag.name);
+    if !is_valid_tag(&name) {
+        return Err(SqlxErrorOrResponse::Response(
+            StatusCode::BAD_REQUEST,
+            "Invalid tag format specified.",
+        ));
+    }
+    let result = query!(
+        "INSERT INTO tags (name) VALUES ($1) ON CONFLICT DO NOTHING",
+        name
+    )
+    .execute(&state.db)
+    .await?;
+    if result.rows_affected() == 0 {
+        return Err(SqlxErrorOrResponse::Response(
+            StatusCode::BAD_REQUEST,
+            "Tag already exists.",
+        ));
+    }
+    Ok(HttpResponse::Ok().body(""))
+}
+
+/// Renames a tag
+#[utoipa::path(
+    put,
+    path = "/api/tags/{name}",
+    params(
+        ("name" = String, Path, description = "Name of tag to rename")
+    ),
+    request_body = NewTag,
+    responses(
+        (status = OK, description = "Tag renamed successfully"),
+        (status = BAD_REQUEST, description = "New name is malformed or taken, or the tag doesn't exist"),
+        (status = UNAUTHORIZED, description = "Not an admin"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[put("/tags/{name}", wrap = "CSHAuth::admin_only()")]
+pub async fn rename_tag(
+    state: Data<AppState>,
+    path: Path<(String,)>,
+    Json(tag): Json<NewTag>,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    let (name,) = path.into_inner();
+    let name = normalize_tag(&name);
+    let new_name = normalize_tag(&tag.name);
+    if !is_valid_tag(&new_name) {
+        return Err(SqlxErrorOrResponse::Response(
+            StatusCode::BAD_REQUEST,
+            "Invalid tag format specified.",
+        ));
+    }
+    let result = match query!("UPDATE tags SET name = $1 WHERE name = $2", new_name, name)
+        .execute(&state.db)
+        .await
+    {
+        Ok(result) => result,
+        Err(sqlx::Error::Database(err)) if err.is_unique_violation() => {
+            return Err(SqlxErrorOrResponse::Response(
+                StatusCode::BAD_REQUEST,
+                "A tag with that name already exists.",
+            ))
+        }
+        Err(err) => return Err(err.into()),
+    };
+    if result.rows_affected() == 0 {
+        return Err(SqlxErrorOrResponse::Response(
+            StatusCode::BAD_REQUEST,
+            "Tag does not exist.",
+        ));
+    }
+    Ok(HttpResponse::Ok().body(""))
+}
+
+/// Deletes a tag and removes it from all quotes
+#[utoipa::path(
+    delete,
+    path = "/api/tags/{name}",
+    params(
+        ("name" = String, Path, description = "Name of tag to delete")
+    ),
+    responses(
+        (status = OK, description = "Tag deleted successfully"),
+        (status = BAD_REQUEST, description = "Tag doesn't exist"),
+        (status = UNAUTHORIZED, description = "Not an admin"),
+        (status = INTERNAL_SERVER_ERROR, description = "Internal Server Error")
+    ),
+)]
+#[delete("/tags/{name}", wrap = "CSHAuth::admin_only()")]
+pub async fn delete_tag(
+    state: Data<AppState>,
+    path: Path<(String,)>,
+) -> Result<HttpResponse, SqlxErrorOrResponse<'static>> {
+    let (name,) = path.into_inner();
+    let name = normalize_tag(&name);
+    let result = query!("DELETE FROM tags WHERE name = $1", name)
+        .execute(&state.db)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(SqlxErrorOrResponse::Response(
+            StatusCode::BAD_REQUEST,
+            "Tag does not exist.",
+        ));
+    }
+    Ok(HttpResponse::Ok().body(""))
+}
+
 /// Get the current version of the backend
 #[utoipa::path(
     get,
@@ -1073,7 +4066,22 @@ pub async fn get_version() -> impl Responder {
 )]
 #[put("/kevlar", wrap = "CSHAuth::disabled()")]
 pub async fn toggle_kevlar(state: Data<AppState>, user: User) -> impl Responder {
-    let result = match query!("insert into kevlar(uid, enabled) values($1, true) on conflict on constraint pkey do update set enabled = not kevlar.enabled, last_modified = now() where kevlar.uid = $1 and kevlar.last_modified + '24 hours' < now() returning enabled", user.preferred_username).fetch_optional(&state.db).await {
+    let result = match query!(
+        "with toggled as (
+            insert into kevlar(uid, enabled) values($1, true)
+            on conflict on constraint pkey do update set enabled = not kevlar.enabled, last_modified = now()
+            where kevlar.uid = $1 and kevlar.last_modified + '24 hours' < now()
+            returning enabled
+        ), audited as (
+            insert into audit_log(actor, action, details)
+            select $1, 'kevlar', case when enabled then 'enabled' else 'disabled' end from toggled
+        )
+        select enabled as \"enabled!\" from toggled",
+        user.preferred_username
+    )
+    .fetch_optional(&state.db)
+    .await
+    {
         Ok(r) => r,
         Err(e) => return HttpResponse::InternalServerError().body(e.to_string()),
     };
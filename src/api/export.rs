@@ -0,0 +1,157 @@
+use crate::schema::api::QuoteResponse;
+
+#[derive(Clone, Copy, Debug)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl ExportFormat {
+    pub fn parse(format: &str) -> Option<Self> {
+        match format {
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            "markdown" | "md" => Some(Self::Markdown),
+            _ => None,
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Markdown => "text/markdown; charset=utf-8",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Csv => "csv",
+            Self::Markdown => "md",
+        }
+    }
+
+    pub fn header(&self) -> String {
+        match self {
+            Self::Json => "[".to_string(),
+            Self::Csv => "id,timestamp,submitter_uid,submitter_name,speaker_uid,speaker_name,body,score,tags\r\n".to_string(),
+            Self::Markdown => "# Quotes\n\n".to_string(),
+        }
+    }
+
+    pub fn footer(&self) -> String {
+        match self {
+            Self::Json => "]".to_string(),
+            Self::Csv | Self::Markdown => String::new(),
+        }
+    }
+
+    /// Formats a single quote. `first` is whether this is the first quote in
+    /// the export, which JSON needs to place separators.
+    pub fn format_quote(&self, quote: &QuoteResponse, first: bool) -> String {
+        match self {
+            Self::Json => {
+                let json = serde_json::to_string(quote).unwrap_or_default();
+                if first {
+                    json
+                } else {
+                    format!(",{json}")
+                }
+            }
+            Self::Csv => quote
+                .shards
+                .iter()
+                .map(|shard| {
+                    [
+                        quote.id.to_string(),
+                        quote.timestamp.to_string(),
+                        csv_field(&quote.submitter.uid),
+                        csv_field(&quote.submitter.cn),
+                        csv_field(&shard.speaker.uid),
+                        csv_field(&shard.speaker.cn),
+                        csv_field(&shard.body),
+                        quote.score.to_string(),
+                        csv_field(&quote.tags.join(";")),
+                    ]
+                    .join(",")
+                        + "\r\n"
+                })
+                .collect(),
+            Self::Markdown => {
+                let mut out = format!(
+                    "## Quote {} ({})\n\n",
+                    quote.id,
+                    quote.timestamp.format("%B %-d, %Y")
+                );
+                let shards = quote
+                    .shards
+                    .iter()
+                    .map(|shard| {
+                        format!(
+                            "**{}:** {}",
+                            markdown_text(&shard.speaker.cn),
+                            markdown_text(&shard.body)
+                        )
+                        .lines()
+                        .map(|line| format!("> {line}"))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n>\n");
+                out.push_str(&shards);
+                out.push_str(&format!(
+                    "\n\nSubmitted by {}",
+                    markdown_text(&quote.submitter.cn)
+                ));
+                if !quote.tags.is_empty() {
+                    out.push_str(&format!(" · {}", markdown_text(&quote.tags.join(", "))));
+                }
+                out.push_str("\n\n");
+                out
+            }
+        }
+    }
+}
+
+/// Escapes a free text cell. Numbers and timestamps are written as they are
+fn csv_field(field: &str) -> String {
+    // Spreadsheets run cells starting with these as formulas
+    let field = if field.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{field}")
+    } else {
+        field.to_string()
+    };
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
+
+/// Escapes text so Markdown renders it as written
+fn markdown_text(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            let mut out = String::with_capacity(line.len());
+            // Whether everything so far is digits, which `1.` would turn into a list
+            let mut numbered = true;
+            for (i, c) in line.chars().enumerate() {
+                if matches!(
+                    c,
+                    '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|' | '~' | '!'
+                ) || (i == 0 && matches!(c, '-' | '+' | '='))
+                    || (i > 0 && numbered && matches!(c, '.' | ')'))
+                {
+                    out.push('\\');
+                }
+                numbered = numbered && c.is_ascii_digit();
+                out.push(c);
+            }
+            out
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -8,11 +8,17 @@ use utoipa::{
 };
 use utoipa_swagger_ui::SwaggerUi;
 
-use crate::{api::endpoints::*, auth::SECURITY_ENABLED, ldap::client::LdapClient};
+use crate::{
+    api::endpoints::*,
+    auth::SECURITY_ENABLED,
+    ldap::client::LdapClient,
+    notify::{notifiers_from_env, Notifier},
+};
 
 pub struct AppState {
     pub db: Pool<Postgres>,
     pub ldap: LdapClient,
+    pub notifiers: Vec<Box<dyn Notifier>>,
 }
 
 pub fn configure_app(cfg: &mut web::ServiceConfig) {
@@ -28,23 +34,54 @@ pub fn configure_app(cfg: &mut web::ServiceConfig) {
     #[derive(OpenApi)]
     #[openapi(
         paths(
+            create_comment,
             create_quote,
+            import_quotes,
+            delete_comment,
             delete_quote,
+            remove_quote,
+            edit_quote,
             favorite_quote,
+            get_comments,
+            get_hidden,
+            get_daily_quote,
+            get_random_quote,
             get_quote,
+            get_quote_history,
             get_quotes,
             get_reports,
+            get_resolved_reports,
             get_users,
+            get_stats,
+            get_notifications,
+            replay_notification,
+            get_audit_log,
+            get_user_profile,
+            export_quotes,
+            get_own_reports,
+            withdraw_report,
+            get_preferences,
+            update_preferences,
             get_version,
             hide_quote,
+            report_comment,
             report_quote,
             resolve_report,
             unfavorite_quote,
+            unhide_quote,
             unvote_quote,
             vote_quote,
+            react_quote,
+            unreact_quote,
+            get_reactions,
             toggle_kevlar,
             get_kevlar,
             delete_kevlar_cache,
+            get_tags,
+            autocomplete_tags,
+            create_tag,
+            rename_tag,
+            delete_tag,
         ),
         modifiers(&SecurityAddon),
         tags(
@@ -77,22 +114,53 @@ pub fn configure_app(cfg: &mut web::ServiceConfig) {
             scope("/api")
                 .wrap(cors)
                 .service(create_quote)
+                .service(import_quotes)
                 .service(get_quotes)
                 .service(get_users)
+                .service(get_stats)
+                .service(get_notifications)
+                .service(replay_notification)
+                .service(get_audit_log)
+                .service(get_user_profile)
+                .service(export_quotes)
+                .service(get_own_reports)
+                .service(withdraw_report)
+                .service(get_preferences)
+                .service(update_preferences)
+                .service(get_daily_quote)
+                .service(get_random_quote)
                 .service(get_quote)
+                .service(get_quote_history)
                 .service(get_reports)
+                .service(get_resolved_reports)
+                .service(get_hidden)
                 .service(delete_quote)
+                .service(remove_quote)
+                .service(edit_quote)
                 .service(hide_quote)
+                .service(unhide_quote)
                 .service(report_quote)
+                .service(get_comments)
+                .service(create_comment)
+                .service(delete_comment)
+                .service(report_comment)
                 .service(resolve_report)
                 .service(vote_quote)
                 .service(unvote_quote)
+                .service(react_quote)
+                .service(unreact_quote)
+                .service(get_reactions)
                 .service(get_version)
                 .service(favorite_quote)
                 .service(unfavorite_quote)
                 .service(toggle_kevlar)
                 .service(get_kevlar)
-                .service(delete_kevlar_cache),
+                .service(delete_kevlar_cache)
+                .service(autocomplete_tags)
+                .service(get_tags)
+                .service(create_tag)
+                .service(rename_tag)
+                .service(delete_tag),
         );
 }
 
@@ -115,5 +183,10 @@ pub async fn get_app_data() -> Data<AppState> {
             .as_str(),
     )
     .await;
-    Data::new(AppState { db, ldap })
+    let notifiers = notifiers_from_env();
+    Data::new(AppState {
+        db,
+        ldap,
+        notifiers,
+    })
 }
@@ -1,6 +1,7 @@
 pub mod app;
 pub mod auth;
 pub mod ldap;
+pub mod notify;
 pub mod utils;
 
 pub mod schema {
@@ -12,5 +13,5 @@ pub mod schema {
 pub mod api {
     pub mod db;
     pub mod endpoints;
-    pub mod pings;
+    pub mod export;
 }
@@ -1,12 +1,16 @@
 use actix_web::{self, middleware::Logger, App, HttpServer};
 use dotenv::dotenv;
-use quotefault_backend::app::{configure_app, get_app_data};
+use quotefault_backend::{
+    app::{configure_app, get_app_data},
+    notify::outbox::run_worker,
+};
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     dotenv().ok();
     env_logger::init();
     let app_data = get_app_data().await;
+    actix_web::rt::spawn(run_worker(app_data.clone()));
     HttpServer::new(move || {
         App::new()
             .wrap(Logger::new(
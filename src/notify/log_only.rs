@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use log::{log, Level};
+
+use super::Notifier;
+
+/// Writes notifications to the log instead of delivering them, for local
+/// development
+pub struct LogNotifier;
+
+#[async_trait]
+impl Notifier for LogNotifier {
+    fn channel(&self) -> &'static str {
+        "log"
+    }
+
+    async fn notify(&self, username: &str, body: &str) -> Result<(), anyhow::Error> {
+        log!(Level::Info, "Notification for {}: {}", username, body);
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use std::env;
+
+use async_trait::async_trait;
+
+pub mod log_only;
+pub mod outbox;
+pub mod pings;
+pub mod smtp;
+pub mod webhook;
+
+/// A channel notifications can be delivered over
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    /// Name of the channel, as used in `QUOTEFAULT_NOTIFIERS`
+    fn channel(&self) -> &'static str;
+
+    async fn notify(&self, username: &str, body: &str) -> Result<(), anyhow::Error>;
+}
+
+/// Builds the notifiers listed in `QUOTEFAULT_NOTIFIERS`, a comma-separated
+/// list of `pings`, `webhook`, `smtp` and `log`. Defaults to `pings` if
+/// `PINGS_SECRET` is set and `log` otherwise. Like the other settings,
+/// blank values are treated as unset.
+pub fn notifiers_from_env() -> Vec<Box<dyn Notifier>> {
+    env::var("QUOTEFAULT_NOTIFIERS")
+        .ok()
+        .filter(|x| !x.trim().is_empty())
+        .unwrap_or_else(|| {
+            if env::var("PINGS_SECRET").is_ok_and(|x| !x.is_empty()) {
+                "pings".to_string()
+            } else {
+                "log".to_string()
+            }
+        })
+        .split(',')
+        .map(|x| x.trim())
+        .filter(|x| !x.is_empty())
+        .map(|name| -> Box<dyn Notifier> {
+            match name {
+                "pings" => Box::new(pings::PingsNotifier::from_env()),
+                "webhook" => Box::new(webhook::WebhookNotifier::from_env()),
+                "smtp" => Box::new(smtp::SmtpNotifier::from_env()),
+                "log" => Box::new(log_only::LogNotifier),
+                _ => panic!("Unknown notifier {name} in QUOTEFAULT_NOTIFIERS"),
+            }
+        })
+        .collect()
+}
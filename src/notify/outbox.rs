@@ -0,0 +1,207 @@
+use std::{collections::BTreeSet, env, time::Duration};
+
+use actix_web::web::Data;
+use lazy_static::lazy_static;
+use log::{log, Level};
+use sqlx::{query, query_as, Postgres, Transaction};
+
+use crate::{
+    app::AppState,
+    ldap,
+    schema::db::{NotificationEvent, OutboxMessage},
+};
+
+lazy_static! {
+    /// Deliveries are given up on and marked dead after this many attempts
+    pub static ref MAX_ATTEMPTS: i32 = env::var("QUOTEFAULT_OUTBOX_MAX_ATTEMPTS")
+        .ok()
+        .and_then(|x| x.parse().ok())
+        .unwrap_or(8);
+}
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+const BATCH_SIZE: i64 = 20;
+/// Channel of outbox rows addressed to every moderator instead of one user
+pub const MODERATORS: &str = "moderators";
+/// LDAP groups whose members are moderators
+const MODERATOR_GROUPS: [&str; 2] = ["eboard", "rtp"];
+
+/// Queues a notification for `username` on every configured channel they
+/// haven't turned off for `event`. Written in the caller's transaction so it
+/// is only sent if the transaction commits.
+pub async fn enqueue(
+    transaction: &mut Transaction<'_, Postgres>,
+    state: &AppState,
+    event: NotificationEvent,
+    username: &str,
+    body: &str,
+) -> Result<(), sqlx::Error> {
+    let channels: Vec<String> = state
+        .notifiers
+        .iter()
+        .map(|x| x.channel().to_string())
+        .collect();
+    query!(
+        "INSERT INTO notification_outbox (username, channel, body)
+        SELECT $1::varchar, c.channel, $3 FROM UNNEST($2::varchar[]) AS c(channel)
+        WHERE NOT EXISTS (
+            SELECT 1 FROM notification_preferences p
+            WHERE p.username = $1::varchar AND p.event = $4 AND p.channel = c.channel AND NOT p.enabled
+        )",
+        username,
+        channels.as_slice(),
+        body,
+        event as NotificationEvent,
+    )
+    .execute(&mut **transaction)
+    .await?;
+    Ok(())
+}
+
+/// Queues a notification for every moderator. Moderators are looked up in
+/// LDAP by the worker, so the caller's transaction never waits on LDAP and
+/// an LDAP outage only delays the notification.
+pub async fn enqueue_moderators(
+    transaction: &mut Transaction<'_, Postgres>,
+    body: &str,
+) -> Result<(), sqlx::Error> {
+    query!(
+        "INSERT INTO notification_outbox (username, channel, body) VALUES (NULL, $1, $2)",
+        MODERATORS,
+        body,
+    )
+    .execute(&mut **transaction)
+    .await?;
+    Ok(())
+}
+
+/// Queues `message` for each moderator individually, marking it delivered in
+/// the same transaction
+async fn fan_out_to_moderators(
+    state: &AppState,
+    message: &OutboxMessage,
+) -> Result<(), anyhow::Error> {
+    let mut moderators = BTreeSet::new();
+    for group in MODERATOR_GROUPS {
+        moderators.extend(
+            ldap::get_group_members(&state.ldap, group)
+                .await?
+                .into_iter()
+                .map(|x| x.uid),
+        );
+    }
+    let mut transaction = state.db.begin().await?;
+    for moderator in moderators {
+        enqueue(
+            &mut transaction,
+            state,
+            NotificationEvent::Reported,
+            &moderator,
+            &message.body,
+        )
+        .await?;
+    }
+    query!(
+        "UPDATE notification_outbox
+        SET status = 'delivered', delivered = NOW(), last_error = NULL
+        WHERE id = $1",
+        message.id,
+    )
+    .execute(&mut *transaction)
+    .await?;
+    transaction.commit().await?;
+    Ok(())
+}
+
+/// Delivers queued notifications forever
+pub async fn run_worker(state: Data<AppState>) {
+    loop {
+        match deliver_batch(&state).await {
+            Ok(0) => actix_web::rt::time::sleep(POLL_INTERVAL).await,
+            Ok(_) => {}
+            Err(err) => {
+                log!(Level::Error, "Outbox worker failed: {}", err);
+                actix_web::rt::time::sleep(POLL_INTERVAL).await;
+            }
+        }
+    }
+}
+
+/// Claims and delivers a batch of due notifications, returning how many were
+/// claimed
+async fn deliver_batch(state: &AppState) -> Result<usize, sqlx::Error> {
+    // Claimed messages are pushed back so other replicas leave them alone
+    // while they are in flight
+    let messages = query_as!(
+        OutboxMessage,
+        "UPDATE notification_outbox
+        SET attempts = attempts + 1, next_attempt = NOW() + INTERVAL '5 minutes'
+        WHERE id IN (
+            SELECT id FROM notification_outbox
+            WHERE status = 'pending' AND next_attempt <= NOW()
+            ORDER BY next_attempt
+            LIMIT $1
+            FOR UPDATE SKIP LOCKED
+        )
+        RETURNING id, username, channel, body, attempts",
+        BATCH_SIZE,
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    for message in &messages {
+        let result = match &message.username {
+            None => fan_out_to_moderators(state, message).await,
+            Some(username) => match state
+                .notifiers
+                .iter()
+                .find(|x| x.channel() == message.channel)
+            {
+                Some(notifier) => notifier.notify(username, &message.body).await,
+                None => Err(anyhow::anyhow!("{} is not configured", message.channel)),
+            },
+        };
+        match result {
+            // Already marked delivered along with the notifications it queued
+            Ok(_) if message.username.is_none() => {}
+            Ok(_) => {
+                query!(
+                    "UPDATE notification_outbox
+                    SET status = 'delivered', delivered = NOW(), last_error = NULL
+                    WHERE id = $1",
+                    message.id,
+                )
+                .execute(&state.db)
+                .await?;
+            }
+            Err(err) => {
+                log!(
+                    Level::Warn,
+                    "Failed to deliver notification {} over {}: {}",
+                    message.id,
+                    message.channel,
+                    err
+                );
+                // 30s, 1m, 2m, ... up to an hour
+                let backoff = 30 * 2_i32.pow((message.attempts - 1).clamp(0, 7) as u32);
+                query!(
+                    "UPDATE notification_outbox
+                    SET status = CASE
+                            WHEN attempts >= $2 THEN 'dead'::notification_status
+                            ELSE 'pending'::notification_status
+                        END,
+                        next_attempt = NOW() + make_interval(secs => LEAST($3, 3600)),
+                        last_error = $4
+                    WHERE id = $1",
+                    message.id,
+                    *MAX_ATTEMPTS,
+                    backoff as f64,
+                    err.to_string(),
+                )
+                .execute(&state.db)
+                .await?;
+            }
+        }
+    }
+    Ok(messages.len())
+}
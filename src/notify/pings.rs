@@ -0,0 +1,57 @@
+use std::env;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use isahc::{AsyncReadResponseExt, Request, RequestExt};
+
+use super::Notifier;
+use crate::schema::pings::PingsBody;
+
+pub struct PingsNotifier {
+    url: String,
+    secret: String,
+    route: String,
+}
+
+impl PingsNotifier {
+    pub fn from_env() -> Self {
+        Self {
+            url: env::var("PINGS_URL")
+                .ok()
+                .filter(|x| !x.is_empty())
+                .unwrap_or("https://pings.csh.rit.edu".to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            secret: env::var("PINGS_SECRET").expect("PINGS_SECRET not set"),
+            route: env::var("PINGS_ROUTE").expect("PINGS_ROUTE not set"),
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for PingsNotifier {
+    fn channel(&self) -> &'static str {
+        "pings"
+    }
+
+    async fn notify(&self, username: &str, body: &str) -> Result<(), anyhow::Error> {
+        let mut response = Request::post(format!("{}/service/route/{}/ping", self.url, self.route))
+            .header("Authorization", format!("Bearer {}", self.secret))
+            .header("Content-Type", "application/json")
+            .body(serde_json::to_vec(&PingsBody {
+                body: body.to_string(),
+                username: username.to_string(),
+            })?)?
+            .send_async()
+            .await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "Failed to ping: {}",
+                response.text().await.unwrap_or_default()
+            ))
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use std::env;
+
+use async_trait::async_trait;
+use lettre::{
+    message::Mailbox, transport::smtp::authentication::Credentials, AsyncSmtpTransport,
+    AsyncTransport, Message, Tokio1Executor,
+};
+
+use super::Notifier;
+
+/// Emails `{username}@{QUOTEFAULT_MAIL_DOMAIN}` through `QUOTEFAULT_SMTP_HOST`
+/// using STARTTLS
+pub struct SmtpNotifier {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    domain: String,
+}
+
+impl SmtpNotifier {
+    pub fn from_env() -> Self {
+        let mut transport = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(
+            env::var("QUOTEFAULT_SMTP_HOST")
+                .expect("QUOTEFAULT_SMTP_HOST not set")
+                .as_str(),
+        )
+        .expect("Invalid QUOTEFAULT_SMTP_HOST");
+        if let Some(port) = env::var("QUOTEFAULT_SMTP_PORT")
+            .ok()
+            .filter(|x| !x.is_empty())
+        {
+            transport = transport.port(port.parse().expect("Invalid QUOTEFAULT_SMTP_PORT"));
+        }
+        if let (Some(username), Ok(password)) = (
+            env::var("QUOTEFAULT_SMTP_USERNAME")
+                .ok()
+                .filter(|x| !x.is_empty()),
+            env::var("QUOTEFAULT_SMTP_PASSWORD"),
+        ) {
+            transport = transport.credentials(Credentials::new(username, password));
+        }
+        Self {
+            transport: transport.build(),
+            from: env::var("QUOTEFAULT_SMTP_FROM")
+                .expect("QUOTEFAULT_SMTP_FROM not set")
+                .parse()
+                .expect("Invalid QUOTEFAULT_SMTP_FROM"),
+            domain: env::var("QUOTEFAULT_MAIL_DOMAIN")
+                .ok()
+                .filter(|x| !x.is_empty())
+                .unwrap_or("csh.rit.edu".to_string()),
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for SmtpNotifier {
+    fn channel(&self) -> &'static str {
+        "smtp"
+    }
+
+    async fn notify(&self, username: &str, body: &str) -> Result<(), anyhow::Error> {
+        let email = Message::builder()
+            .from(self.from.clone())
+            .to(format!("{}@{}", username, self.domain).parse()?)
+            .subject("Quotefault")
+            .body(body.to_string())?;
+        self.transport.send(email).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use std::env;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use isahc::{Request, RequestExt};
+
+use super::Notifier;
+use crate::schema::pings::PingsBody;
+
+/// POSTs `{"username": ..., "body": ...}` to `QUOTEFAULT_WEBHOOK_URL`, with
+/// `QUOTEFAULT_WEBHOOK_SECRET` as a bearer token if set
+pub struct WebhookNotifier {
+    url: String,
+    secret: Option<String>,
+}
+
+impl WebhookNotifier {
+    pub fn from_env() -> Self {
+        Self {
+            url: env::var("QUOTEFAULT_WEBHOOK_URL").expect("QUOTEFAULT_WEBHOOK_URL not set"),
+            secret: env::var("QUOTEFAULT_WEBHOOK_SECRET")
+                .ok()
+                .filter(|x| !x.is_empty()),
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    fn channel(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn notify(&self, username: &str, body: &str) -> Result<(), anyhow::Error> {
+        let mut request = Request::post(&self.url).header("Content-Type", "application/json");
+        if let Some(secret) = &self.secret {
+            request = request.header("Authorization", format!("Bearer {}", secret));
+        }
+        let response = request
+            .body(serde_json::to_vec(&PingsBody {
+                body: body.to_string(),
+                username: username.to_string(),
+            })?)?
+            .send_async()
+            .await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(anyhow!("Webhook responded with {}", response.status()))
+        }
+    }
+}
@@ -1,10 +1,14 @@
-use crate::schema::db::Vote;
+use crate::schema::db::{
+    AuditAction, NotificationEvent, NotificationStatus, ReportCategory, ReportOutcome, Vote,
+};
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 
 #[derive(Deserialize, Debug, ToSchema)]
 pub struct NewQuote {
     pub shards: Vec<NewQuoteShard>,
+    /// Leaving this out when editing keeps the quote's current tags
+    pub tags: Option<Vec<String>>,
 }
 
 #[derive(Deserialize, Debug, ToSchema)]
@@ -13,15 +17,62 @@ pub struct NewQuoteShard {
     pub speaker: String,
 }
 
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ImportQuote {
+    pub submitter: String,
+    pub timestamp: chrono::NaiveDateTime,
+    pub shards: Vec<NewQuoteShard>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct PreferenceResponse {
+    pub event: NotificationEvent,
+    pub channel: String,
+    pub enabled: bool,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct NewComment {
+    pub body: String,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct NewTag {
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug, IntoParams)]
+pub struct TagParams {
+    pub q: Option<String>,
+    pub limit: Option<i64>,
+}
+
 #[derive(Deserialize, Debug, ToSchema)]
 pub struct Reason {
     pub reason: String,
 }
 
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct NewReport {
+    #[serde(default)]
+    pub category: ReportCategory,
+    pub reason: Option<String>,
+}
+
+/// Sent in the body so moderator notes stay out of URLs and access logs
+#[derive(Deserialize, Debug, Default, ToSchema)]
+pub struct Resolution {
+    pub outcome: Option<ReportOutcome>,
+    pub notes: Option<String>,
+}
+
 #[derive(Deserialize, Debug, IntoParams)]
 pub struct FetchParams {
     pub q: Option<String>,
     pub lt: Option<i32>,
+    pub cursor: Option<String>,
     pub limit: Option<i64>,
     pub submitter: Option<String>,
     pub speaker: Option<String>,
@@ -30,12 +81,84 @@ pub struct FetchParams {
     pub favorited: Option<bool>,
     pub sort: Option<String>,
     pub sort_direction: Option<bool>,
+    pub tag: Option<String>,
+}
+
+#[derive(Deserialize, Debug, IntoParams)]
+pub struct HiddenParams {
+    pub lt: Option<i32>,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    pub actor: Option<String>,
+    pub after: Option<chrono::NaiveDateTime>,
+    pub before: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Deserialize, Debug, IntoParams)]
+pub struct AuditParams {
+    pub lt: Option<i32>,
+    pub limit: Option<i64>,
+    pub actor: Option<String>,
+    pub quote_id: Option<i32>,
+    pub after: Option<chrono::NaiveDateTime>,
+    pub before: Option<chrono::NaiveDateTime>,
+}
+
+/// A page of results along with the total number of matches and the filters
+/// that produced them
+#[derive(Serialize, Debug, ToSchema)]
+pub struct PaginatedResponse<T, F> {
+    pub items: Vec<T>,
+    pub total: i64,
+    /// Pass as `cursor` to fetch the next page. `null` when there are no more results.
+    pub next_cursor: Option<String>,
+    pub filters: F,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct QuoteFilters {
+    pub q: Option<String>,
+    pub submitter: Option<String>,
+    pub speaker: Option<String>,
+    pub involved: Option<String>,
+    pub hidden: Option<bool>,
+    pub favorited: bool,
+    pub tag: Option<String>,
+    pub sort: String,
+    pub sort_direction: bool,
+    pub limit: i64,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct HiddenFilters {
+    pub actor: Option<String>,
+    pub after: Option<chrono::NaiveDateTime>,
+    pub before: Option<chrono::NaiveDateTime>,
+    pub limit: i64,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ReportFilters {
+    pub category: Option<ReportCategory>,
+    pub limit: i64,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ResolvedReportFilters {
+    pub category: Option<ReportCategory>,
+    pub outcome: Option<ReportOutcome>,
+    pub resolver: Option<String>,
+    pub quote_id: Option<i32>,
+    pub after: Option<chrono::NaiveDateTime>,
+    pub before: Option<chrono::NaiveDateTime>,
+    pub limit: i64,
 }
 
 #[derive(Serialize, Debug, ToSchema)]
 pub struct Hidden {
     pub reason: String,
     pub actor: UserResponse,
+    pub timestamp: Option<chrono::NaiveDateTime>,
 }
 
 #[derive(Serialize, Debug, ToSchema)]
@@ -48,21 +171,58 @@ pub struct QuoteResponse {
     pub score: i64,
     pub hidden: Option<Hidden>,
     pub favorited: bool,
+    pub tags: Vec<String>,
+    pub comment_count: i64,
+    pub reactions: Vec<ReactionResponse>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct QuoteRevisionResponse {
+    pub revision: i32,
+    /// When this version was written
+    pub timestamp: chrono::NaiveDateTime,
+    pub current: bool,
+    pub shards: Vec<QuoteShardResponse>,
+    /// Shards that differ from the previous version. Empty for the first one.
+    pub changes: Vec<ShardChangeResponse>,
 }
 
+/// A shard that was added (`before` is `null`), removed (`after` is `null`)
+/// or changed between two versions of a quote
 #[derive(Serialize, Debug, ToSchema)]
+pub struct ShardChangeResponse {
+    pub index: i32,
+    pub before: Option<QuoteShardResponse>,
+    pub after: Option<QuoteShardResponse>,
+}
+
+#[derive(Serialize, Clone, Debug, ToSchema)]
 pub struct QuoteShardResponse {
     pub body: String,
     pub speaker: UserResponse,
 }
 
+#[derive(Serialize, Debug, ToSchema)]
+pub struct CommentResponse {
+    pub id: i32,
+    pub author: UserResponse,
+    pub body: String,
+    pub timestamp: chrono::NaiveDateTime,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct TagResponse {
+    pub name: String,
+    pub count: i64,
+}
+
 #[derive(Serialize, Clone, Debug, ToSchema)]
 pub struct UserResponse {
     pub cn: String,
     pub uid: String,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, ToSchema)]
 pub struct ReportedQuoteResponse {
     pub quote_id: i32,
     pub reports: Vec<ReportResponse>,
@@ -70,14 +230,76 @@ pub struct ReportedQuoteResponse {
 
 #[derive(Serialize, Debug, ToSchema)]
 pub struct ReportResponse {
-    pub reason: String,
+    pub category: ReportCategory,
+    pub reason: Option<String>,
+    pub timestamp: chrono::NaiveDateTime,
+    pub id: i32,
+    pub comment_id: Option<i32>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ResolvedReportResponse {
+    pub id: i32,
+    pub quote_id: i32,
+    pub comment_id: Option<i32>,
+    pub category: ReportCategory,
+    pub reason: Option<String>,
     pub timestamp: chrono::NaiveDateTime,
+    pub resolver: String,
+    pub resolved: Option<chrono::NaiveDateTime>,
+    pub outcome: Option<ReportOutcome>,
+    pub notes: Option<String>,
+    /// Whether the quote is hidden now, not whether this resolution hid it
+    pub quote_currently_hidden: bool,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportStatus {
+    Open,
+    Resolved,
+}
+
+/// A report as seen by the user who filed it. Never includes who resolved it
+/// or their notes.
+#[derive(Serialize, Debug, ToSchema)]
+pub struct OwnReportResponse {
     pub id: i32,
+    pub quote_id: i32,
+    pub comment_id: Option<i32>,
+    pub category: ReportCategory,
+    pub reason: Option<String>,
+    pub timestamp: chrono::NaiveDateTime,
+    pub status: ReportStatus,
+    pub resolved: Option<chrono::NaiveDateTime>,
+    pub outcome: Option<ReportOutcome>,
 }
 
 #[derive(Deserialize, Debug, IntoParams)]
 pub struct ResolveParams {
     pub hide: Option<bool>,
+    pub unhide: Option<bool>,
+    /// Resolves the reports on this comment instead of the ones on the quote
+    pub comment_id: Option<i32>,
+}
+
+#[derive(Deserialize, Debug, IntoParams)]
+pub struct ReportParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    pub category: Option<ReportCategory>,
+}
+
+#[derive(Deserialize, Debug, IntoParams)]
+pub struct ResolvedReportParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    pub category: Option<ReportCategory>,
+    pub outcome: Option<ReportOutcome>,
+    pub resolver: Option<String>,
+    pub quote_id: Option<i32>,
+    pub after: Option<chrono::NaiveDateTime>,
+    pub before: Option<chrono::NaiveDateTime>,
 }
 
 #[derive(Deserialize, Debug, IntoParams)]
@@ -85,6 +307,117 @@ pub struct VoteParams {
     pub vote: Vote,
 }
 
+#[derive(Deserialize, Debug, IntoParams)]
+pub struct ReactionParams {
+    pub emoji: String,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ReactionResponse {
+    pub emoji: String,
+    pub count: i64,
+    pub reacted: bool,
+}
+
+#[derive(Deserialize, Debug, IntoParams)]
+pub struct ExportParams {
+    /// One of `json`, `csv` or `markdown`
+    pub format: Option<String>,
+}
+
+#[derive(Deserialize, Debug, IntoParams)]
+pub struct OutboxParams {
+    pub status: Option<NotificationStatus>,
+    pub lt: Option<i32>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Deserialize, Debug, IntoParams)]
+pub struct RandomParams {
+    pub q: Option<String>,
+    pub submitter: Option<String>,
+    pub speaker: Option<String>,
+    pub involved: Option<String>,
+    pub tag: Option<String>,
+    pub min_score: Option<i64>,
+}
+
+#[derive(Deserialize, Debug, IntoParams)]
+pub struct StatsParams {
+    /// One of `day`, `week`, `month`, `year` or `all`
+    pub window: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct UserStatResponse {
+    pub user: UserResponse,
+    pub count: i64,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct QuoteStatResponse {
+    pub id: i32,
+    pub count: i64,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct StatsResponse {
+    pub window: String,
+    pub since: Option<chrono::NaiveDateTime>,
+    pub most_quoted: Vec<UserStatResponse>,
+    pub top_submitters: Vec<UserStatResponse>,
+    pub top_quotes: Vec<QuoteStatResponse>,
+    pub most_favorited: Vec<QuoteStatResponse>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct UserProfileResponse {
+    pub uid: String,
+    pub cn: String,
+    pub quotes_said: i64,
+    pub quotes_submitted: i64,
+    pub total_score: i64,
+    pub favorites_received: i64,
+    pub co_speakers: Vec<UserStatResponse>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ImportErrorResponse {
+    pub row: usize,
+    pub error: String,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ImportResponse {
+    pub imported: Vec<i32>,
+    pub errors: Vec<ImportErrorResponse>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct OutboxResponse {
+    pub id: i32,
+    pub username: Option<String>,
+    pub channel: String,
+    pub body: String,
+    pub status: NotificationStatus,
+    pub attempts: i32,
+    pub next_attempt: chrono::NaiveDateTime,
+    pub last_error: Option<String>,
+    pub timestamp: chrono::NaiveDateTime,
+    pub delivered: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct AuditResponse {
+    pub id: i32,
+    pub actor: String,
+    pub action: AuditAction,
+    pub quote_id: Option<i32>,
+    pub details: Option<String>,
+    pub timestamp: chrono::NaiveDateTime,
+}
+
 #[derive(Serialize, Debug, ToSchema)]
 pub struct VersionResponse {
     pub revision: String,
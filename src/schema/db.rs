@@ -16,7 +16,24 @@ pub struct QuoteShard {
     pub score: i64,
     pub hidden_reason: Option<String>,
     pub hidden_actor: Option<String>,
+    pub hidden_timestamp: Option<chrono::NaiveDateTime>,
     pub favorited: bool,
+    pub comment_count: i64,
+    pub reaction_emoji: Vec<String>,
+    pub reaction_counts: Vec<i64>,
+    pub user_reactions: Vec<String>,
+    pub tags: Vec<String>,
+    pub sort_key: Option<f64>,
+    pub total: Option<i64>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct RevisionShard {
+    pub revision: i32,
+    pub index: i32,
+    pub body: String,
+    pub speaker: String,
+    pub timestamp: chrono::NaiveDateTime,
 }
 
 #[derive(Serialize, Debug)]
@@ -26,9 +43,47 @@ pub struct ReportedQuoteShard {
     pub quote_timestamp: chrono::NaiveDateTime,
     pub quote_hidden: bool,
     pub report_id: i32,
-    pub report_reason: String,
+    pub report_category: ReportCategory,
+    pub report_reason: Option<String>,
     pub report_timestamp: chrono::NaiveDateTime,
     pub report_resolver: Option<String>,
+    pub report_comment_id: Option<i32>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ResolvedReport {
+    pub id: i32,
+    pub quote_id: i32,
+    pub comment_id: Option<i32>,
+    pub category: ReportCategory,
+    pub reason: Option<String>,
+    pub timestamp: chrono::NaiveDateTime,
+    pub resolver: String,
+    pub resolved: Option<chrono::NaiveDateTime>,
+    pub outcome: Option<ReportOutcome>,
+    pub notes: Option<String>,
+    pub quote_currently_hidden: bool,
+}
+
+#[derive(Serialize, Debug)]
+pub struct OwnReport {
+    pub id: i32,
+    pub quote_id: i32,
+    pub comment_id: Option<i32>,
+    pub category: ReportCategory,
+    pub reason: Option<String>,
+    pub timestamp: chrono::NaiveDateTime,
+    pub is_resolved: bool,
+    pub resolved: Option<chrono::NaiveDateTime>,
+    pub outcome: Option<ReportOutcome>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Comment {
+    pub id: i32,
+    pub author: String,
+    pub body: String,
+    pub timestamp: chrono::NaiveDateTime,
 }
 
 #[derive(Clone, Debug, PartialEq, PartialOrd, sqlx::Type, Serialize, Deserialize, ToSchema)]
@@ -38,3 +93,124 @@ pub enum Vote {
     Upvote,
     Downvote,
 }
+
+#[derive(Clone, Debug, PartialEq, sqlx::Type, Serialize, Deserialize, ToSchema)]
+#[sqlx(type_name = "notification_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationStatus {
+    Pending,
+    Delivered,
+    Dead,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, sqlx::Type, Serialize, Deserialize, ToSchema)]
+#[sqlx(type_name = "report_category", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReportCategory {
+    Harassment,
+    PrivateInfo,
+    Inaccurate,
+    #[default]
+    Other,
+}
+
+impl ReportCategory {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Harassment => "Harassment",
+            Self::PrivateInfo => "Private information",
+            Self::Inaccurate => "Inaccurate",
+            Self::Other => "Other",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, sqlx::Type, Serialize, Deserialize, ToSchema)]
+#[sqlx(type_name = "report_outcome", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ReportOutcome {
+    Dismissed,
+    Hidden,
+    Unhidden,
+    Other,
+    /// The reported comment was deleted
+    Removed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, sqlx::Type, Serialize, Deserialize, ToSchema)]
+#[sqlx(type_name = "audit_action", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum AuditAction {
+    Hide,
+    Unhide,
+    Resolve,
+    Delete,
+    Kevlar,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, sqlx::Type, Serialize, Deserialize, ToSchema)]
+#[sqlx(type_name = "notification_event", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationEvent {
+    Quoted,
+    Upvoted,
+    Moderated,
+    Commented,
+    Reported,
+}
+
+impl NotificationEvent {
+    pub const ALL: [Self; 5] = [
+        Self::Quoted,
+        Self::Upvoted,
+        Self::Moderated,
+        Self::Commented,
+        Self::Reported,
+    ];
+}
+
+impl sqlx::postgres::PgHasArrayType for NotificationEvent {
+    fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+        sqlx::postgres::PgTypeInfo::with_name("_notification_event")
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct OutboxMessage {
+    pub id: i32,
+    /// `None` for notifications to every moderator
+    pub username: Option<String>,
+    pub channel: String,
+    pub body: String,
+    pub attempts: i32,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Preference {
+    pub event: NotificationEvent,
+    pub channel: String,
+    pub enabled: bool,
+}
+
+#[derive(Serialize, Debug)]
+pub struct UserCount {
+    pub uid: String,
+    pub count: i64,
+}
+
+/// One entry on a leaderboard, keyed by user or by quote depending on the board
+#[derive(Serialize, Debug)]
+pub struct BoardCount {
+    pub board: String,
+    pub uid: Option<String>,
+    pub id: Option<i32>,
+    pub count: i64,
+}
+
+#[derive(Serialize, Debug)]
+pub struct UserStats {
+    pub quotes_said: i64,
+    pub quotes_submitted: i64,
+    pub total_score: i64,
+    pub favorites_received: i64,
+}
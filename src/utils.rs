@@ -1,3 +1,61 @@
+use std::env;
+
+use base64::{engine::general_purpose, Engine as _};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+
+lazy_static! {
+    /// Emoji users may react to quotes with, configured as a comma-separated
+    /// list in `QUOTEFAULT_REACTIONS`.
+    pub static ref REACTION_ALLOWLIST: Vec<String> = env::var("QUOTEFAULT_REACTIONS")
+        .ok()
+        .filter(|x| !x.trim().is_empty())
+        .unwrap_or("😂,😮,😢,❤️,🔥,💀".to_string())
+        .split(',')
+        .map(|x| x.trim().to_string())
+        .filter(|x| !x.is_empty())
+        .collect();
+    /// Upvote counts that the people in a quote are notified about reaching,
+    /// configured as a comma-separated list in `QUOTEFAULT_SCORE_MILESTONES`.
+    pub static ref SCORE_MILESTONES: Vec<i32> = env::var("QUOTEFAULT_SCORE_MILESTONES")
+        .ok()
+        .filter(|x| !x.trim().is_empty())
+        .unwrap_or("10,25,50".to_string())
+        .split(',')
+        .filter_map(|x| x.trim().parse().ok())
+        .collect();
+}
+
 pub fn is_valid_username(username: &str) -> bool {
     username.len() <= 32 && username.chars().any(|x| x.is_ascii_alphanumeric())
 }
+
+pub fn is_valid_tag(tag: &str) -> bool {
+    !tag.is_empty()
+        && tag.len() <= 32
+        && tag
+            .chars()
+            .all(|x| x.is_ascii_alphanumeric() || x == ' ' || x == '-' || x == '_')
+}
+
+/// Position of the last quote on a page of `/api/quotes`, handed back to the
+/// client as an opaque token.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QuoteCursor {
+    pub sort: String,
+    pub sort_direction: bool,
+    pub key: f64,
+    pub id: i32,
+}
+
+impl QuoteCursor {
+    pub fn encode(&self) -> String {
+        general_purpose::URL_SAFE_NO_PAD.encode(serde_json::to_vec(self).unwrap())
+    }
+
+    pub fn decode(cursor: &str) -> Result<Self, anyhow::Error> {
+        Ok(serde_json::from_slice(
+            &general_purpose::URL_SAFE_NO_PAD.decode(cursor)?,
+        )?)
+    }
+}